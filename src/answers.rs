@@ -0,0 +1,236 @@
+//! Known answers, kept in a data file rather than in the tests so other
+//! accounts' inputs can be checked without touching the source.
+//!
+//! Each line of the file is `<day> <part> <key> <answer>`, where the key is
+//! either `hash:<input hash>` (see [`crate::input::hash`]) or
+//! `profile:<name>`. Blank lines and lines starting with `#` are ignored.
+//! Each year has its own file; see [`file_for`].
+
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::Path;
+
+pub const DEFAULT_FILE: &str = "answers.txt";
+
+/// [`DEFAULT_FILE`] for the default year, `answers/<year>.txt` for others.
+pub fn file_for(year: u32) -> String {
+    if year == crate::years::DEFAULT {
+        DEFAULT_FILE.to_string()
+    } else {
+        format!("answers/{year}.txt")
+    }
+}
+
+/// profile the answers for the original inputs are filed under
+pub const DEFAULT_PROFILE: &str = "default";
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Key {
+    Hash(String),
+    Profile(String),
+}
+
+impl fmt::Display for Key {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Key::Hash(h) => write!(f, "hash:{h}"),
+            Key::Profile(p) => write!(f, "profile:{p}"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Entry {
+    pub day: u32,
+    pub part: u32,
+    pub key: Key,
+    pub answer: String,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct Registry {
+    entries: Vec<Entry>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Verdict {
+    Match,
+    Mismatch { expected: String },
+    Unknown,
+}
+
+impl Registry {
+    /// Errors name the offending line.
+    pub fn parse(text: &str) -> Result<Self, String> {
+        let mut entries = Vec::new();
+
+        for (i, line) in text.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            match parse_entry(line) {
+                Some(entry) => entries.push(entry),
+                None => {
+                    return Err(format!(
+                        "line {}: expected `<day> <part> hash:<hex>|profile:<name> <answer>`",
+                        i + 1
+                    ))
+                }
+            }
+        }
+
+        Ok(Self { entries })
+    }
+
+    /// A missing file is an empty registry.
+    pub fn load(path: &Path) -> io::Result<Self> {
+        match fs::read_to_string(path) {
+            Ok(text) => Self::parse(&text).map_err(|e| {
+                io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    format!("{}: {e}", path.display()),
+                )
+            }),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(e) => Err(io::Error::new(e.kind(), format!("{}: {e}", path.display()))),
+        }
+    }
+
+    /// The answer recorded for this exact input, else the one filed under
+    /// `profile`.
+    pub fn lookup(&self, day: u32, part: u32, hash: &str, profile: &str) -> Option<&str> {
+        let find = |key: &Key| {
+            self.entries
+                .iter()
+                .find(|e| e.day == day && e.part == part && e.key == *key)
+                .map(|e| e.answer.as_str())
+        };
+        find(&Key::Hash(hash.to_string())).or_else(|| find(&Key::Profile(profile.to_string())))
+    }
+
+    pub fn check(&self, day: u32, part: u32, hash: &str, profile: &str, answer: &str) -> Verdict {
+        match self.lookup(day, part, hash, profile) {
+            Some(expected) if expected == answer => Verdict::Match,
+            Some(expected) => Verdict::Mismatch {
+                expected: expected.to_string(),
+            },
+            None => Verdict::Unknown,
+        }
+    }
+
+    /// Adds an entry, replacing any with the same day, part and key.
+    pub fn insert(&mut self, entry: Entry) {
+        self.entries
+            .retain(|e| !(e.day == entry.day && e.part == entry.part && e.key == entry.key));
+        self.entries.push(entry);
+        self.entries.sort_by_key(|e| (e.day, e.part));
+    }
+}
+
+fn parse_entry(line: &str) -> Option<Entry> {
+    let [day, part, key, answer] = line.split_whitespace().collect::<Vec<_>>()[..] else {
+        return None;
+    };
+
+    Some(Entry {
+        day: day.parse().ok().filter(|d| (1..=25).contains(d))?,
+        part: part.parse().ok().filter(|p| (1..=2).contains(p))?,
+        key: match key.split_once(':')? {
+            ("hash", h) => Key::Hash(h.to_string()),
+            ("profile", p) => Key::Profile(p.to_string()),
+            _ => return None,
+        },
+        answer: answer.to_string(),
+    })
+}
+
+impl fmt::Display for Registry {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(
+            f,
+            "# <day> <part> hash:<input hash>|profile:<name> <answer>"
+        )?;
+        for e in &self.entries {
+            writeln!(f, "{:02} {} {} {}", e.day, e.part, e.key, e.answer)?;
+        }
+        Ok(())
+    }
+}
+
+#[test]
+fn lookup() {
+    let mut registry = Registry::parse(
+        "# comment
+01 1 profile:default 54
+01 1 hash:00ff 55
+
+02 2 profile:alice 7",
+    )
+    .unwrap();
+
+    assert_eq!(registry.lookup(1, 1, "00ff", "default"), Some("55"));
+    assert_eq!(registry.lookup(1, 1, "1234", "default"), Some("54"));
+    assert_eq!(
+        registry.check(2, 2, "1234", "default", "7"),
+        Verdict::Unknown
+    );
+    assert_eq!(registry.check(2, 2, "1234", "alice", "7"), Verdict::Match);
+
+    registry.insert(Entry {
+        day: 1,
+        part: 1,
+        key: Key::Hash("00ff".to_string()),
+        answer: "56".to_string(),
+    });
+    let registry = Registry::parse(&registry.to_string()).unwrap();
+    assert_eq!(
+        registry.check(1, 1, "00ff", "default", "55"),
+        Verdict::Mismatch {
+            expected: "56".to_string()
+        }
+    );
+
+    assert!(Registry::parse("01 3 profile:default 1").is_err());
+    assert!(Registry::parse("01 1 default 1").is_err());
+}
+
+/// Checks every day of every year whose input is present against that
+/// year's registry. Answers the registry does not know yet are skipped.
+#[test]
+fn answers() {
+    for &(year, days) in crate::years::YEARS {
+        let path = Path::new(env!("CARGO_MANIFEST_DIR")).join(file_for(year));
+        let registry = Registry::load(&path).unwrap();
+
+        for solution in days {
+            let day = solution.day();
+            let Some(input) = crate::input::for_test(year, day) else {
+                continue;
+            };
+            let hash = crate::input::hash(&input);
+
+            let run = solution.run(&input, solution.parts());
+            assert!(
+                matches!(run.parse.value, Some(Ok(()))),
+                "{year} day {day} parse"
+            );
+            for (part, answer) in run.answers {
+                let answer = answer
+                    .value
+                    .unwrap_or_else(|| panic!("{year} day {day} part {part} failed"));
+                match registry.check(day, part, &hash, DEFAULT_PROFILE, &answer) {
+                    Verdict::Match => (),
+                    Verdict::Unknown => {
+                        eprintln!("skipping {year} day {day} part {part}: no known answer")
+                    }
+                    Verdict::Mismatch { expected } => {
+                        panic!("{year} day {day} part {part}: got {answer}, expected {expected}")
+                    }
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,164 @@
+//! Repeated timing of each phase of a day, and the JSON file the results are
+//! kept in so a later run can be compared against them.
+//!
+//! The file is written one record per line, which is what [`read_json`]
+//! relies on; it only needs to read files written by [`write_json`].
+
+use std::fmt::Write;
+use std::time::Duration;
+
+use crate::{DynSolution, ParseError};
+
+/// Timings of one phase (`parse`, `part1` or `part2`) of a day.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Record {
+    pub day: u32,
+    pub phase: String,
+    pub min: Duration,
+    pub median: Duration,
+    pub max: Duration,
+}
+
+impl Record {
+    fn new(day: u32, phase: &str, samples: &mut [Duration]) -> Self {
+        samples.sort_unstable();
+        Self {
+            day,
+            phase: phase.to_string(),
+            min: samples[0],
+            median: samples[samples.len() / 2],
+            max: samples[samples.len() - 1],
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum BenchError {
+    Parse(ParseError),
+    /// a phase panicked on the given run
+    Panicked(&'static str),
+}
+
+/// Runs `solution` on `input` `runs` times (at least once), timing parse and
+/// each of `parts` separately.
+pub fn bench(
+    solution: &dyn DynSolution,
+    input: &str,
+    parts: &[u32],
+    runs: usize,
+) -> Result<Vec<Record>, BenchError> {
+    let mut parse = Vec::new();
+    let mut answers: Vec<(u32, Vec<Duration>)> = Vec::new();
+
+    for _ in 0..runs.max(1) {
+        let run = solution.run(input, parts);
+        match run.parse.value {
+            Some(Ok(())) => parse.push(run.parse.elapsed),
+            Some(Err(e)) => return Err(BenchError::Parse(e)),
+            None => return Err(BenchError::Panicked("parse")),
+        }
+
+        for (part, answer) in run.answers {
+            if answer.value.is_none() {
+                return Err(BenchError::Panicked(phase_name(part)));
+            }
+            match answers.iter_mut().find(|(p, _)| *p == part) {
+                Some((_, samples)) => samples.push(answer.elapsed),
+                None => answers.push((part, vec![answer.elapsed])),
+            }
+        }
+    }
+
+    let day = solution.day();
+    let mut records = vec![Record::new(day, "parse", &mut parse)];
+    for (part, mut samples) in answers {
+        records.push(Record::new(day, phase_name(part), &mut samples));
+    }
+
+    Ok(records)
+}
+
+fn phase_name(part: u32) -> &'static str {
+    match part {
+        1 => "part1",
+        _ => "part2",
+    }
+}
+
+pub fn write_json(runs: usize, records: &[Record]) -> String {
+    let mut out = format!("{{\n  \"runs\": {runs},\n  \"results\": [\n");
+    for (i, r) in records.iter().enumerate() {
+        let _ = write!(
+            out,
+            "    {{\"day\": {}, \"phase\": \"{}\", \"min_ns\": {}, \"median_ns\": {}, \"max_ns\": {}}}",
+            r.day,
+            r.phase,
+            r.min.as_nanos(),
+            r.median.as_nanos(),
+            r.max.as_nanos(),
+        );
+        out.push_str(if i + 1 < records.len() { ",\n" } else { "\n" });
+    }
+    out.push_str("  ]\n}\n");
+    out
+}
+
+/// Reads back the records of a file written by [`write_json`]; lines that
+/// are not records are skipped.
+pub fn read_json(text: &str) -> Vec<Record> {
+    text.lines()
+        .filter_map(|line| {
+            let nanos = |key| field(line, key)?.parse().ok().map(Duration::from_nanos);
+            Some(Record {
+                day: field(line, "day")?.parse().ok()?,
+                phase: field(line, "phase")?.trim_matches('"').to_string(),
+                min: nanos("min_ns")?,
+                median: nanos("median_ns")?,
+                max: nanos("max_ns")?,
+            })
+        })
+        .collect()
+}
+
+/// raw value of `"key": value` on a single-line object
+fn field<'a>(line: &'a str, key: &str) -> Option<&'a str> {
+    let start = line.find(&format!("\"{key}\": "))? + key.len() + 4;
+    let rest = &line[start..];
+    let end = rest.find([',', '}']).unwrap_or(rest.len());
+    Some(rest[..end].trim())
+}
+
+/// Relative change of the median against the matching baseline record, if
+/// there is one.
+pub fn change(record: &Record, baseline: &[Record]) -> Option<f64> {
+    let base = baseline
+        .iter()
+        .find(|b| b.day == record.day && b.phase == record.phase)?;
+    let base = base.median.as_secs_f64();
+    (base > 0.0).then(|| record.median.as_secs_f64() / base - 1.0)
+}
+
+#[test]
+fn stats() {
+    let ms = Duration::from_millis;
+    let mut samples = [ms(5), ms(1), ms(9), ms(3), ms(4)];
+    let r = Record::new(7, "part1", &mut samples);
+    assert_eq!((r.min, r.median, r.max), (ms(1), ms(4), ms(9)));
+}
+
+#[test]
+fn json_round_trip() {
+    let records = vec![
+        Record::new(1, "parse", &mut [Duration::from_nanos(120)]),
+        Record::new(1, "part1", &mut [Duration::from_micros(3)]),
+        Record::new(25, "part1", &mut [Duration::from_secs(2)]),
+    ];
+    let json = write_json(1, &records);
+    assert_eq!(read_json(&json), records);
+
+    let mut slower = records[2].clone();
+    slower.median = Duration::from_secs(3);
+    assert_eq!(change(&slower, &records), Some(0.5));
+    slower.day = 24;
+    assert_eq!(change(&slower, &records), None);
+}
@@ -0,0 +1,115 @@
+//! Cycle finding for simulations that step a state until it repeats, so the
+//! state after a huge number of steps can be read off the first loop.
+
+use std::collections::HashMap;
+use std::hash::Hash;
+
+/// Shape of the sequence `x0, f(x0), f(f(x0)), ...`: states from index
+/// `prefix` on repeat every `len` steps.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Cycle {
+    pub prefix: usize,
+    pub len: usize,
+}
+
+impl Cycle {
+    /// The index below `prefix + len` holding the same state as index `n`.
+    pub fn reduce(&self, n: usize) -> usize {
+        if n < self.prefix {
+            n
+        } else {
+            self.prefix + (n - self.prefix) % self.len
+        }
+    }
+
+    /// State at index `n`, replayed from `start`.
+    pub fn state_at<S: Clone>(&self, start: &S, mut step: impl FnMut(&S) -> S, n: usize) -> S {
+        (0..self.reduce(n)).fold(start.clone(), |s, _| step(&s))
+    }
+}
+
+/// Brent's algorithm: only a couple of states are held at a time, at the
+/// cost of stepping through the sequence about three times.
+pub fn brent<S: Clone + Eq>(start: &S, mut step: impl FnMut(&S) -> S) -> Cycle {
+    // find the length: the tortoise teleports to the hare at powers of two
+    let (mut power, mut len) = (1, 1);
+    let mut tortoise = start.clone();
+    let mut hare = step(start);
+    while tortoise != hare {
+        if power == len {
+            tortoise = hare.clone();
+            power *= 2;
+            len = 0;
+        }
+        hare = step(&hare);
+        len += 1;
+    }
+
+    // then the prefix: walk two pointers `len` apart until they meet
+    let mut tortoise = start.clone();
+    let mut hare = (0..len).fold(start.clone(), |s, _| step(&s));
+    let mut prefix = 0;
+    while tortoise != hare {
+        tortoise = step(&tortoise);
+        hare = step(&hare);
+        prefix += 1;
+    }
+
+    Cycle { prefix, len }
+}
+
+/// Every state of a sequence up to its first repeat, as found by [`hashed`].
+#[derive(Debug, Clone)]
+pub struct History<S> {
+    pub cycle: Cycle,
+    states: Vec<S>,
+}
+
+impl<S> History<S> {
+    pub fn state_at(&self, n: usize) -> &S {
+        &self.states[self.cycle.reduce(n)]
+    }
+}
+
+/// Steps once through the sequence, remembering every state.
+pub fn hashed<S: Clone + Hash + Eq>(start: S, mut step: impl FnMut(&S) -> S) -> History<S> {
+    let mut seen = HashMap::new();
+    let mut states = Vec::new();
+    let mut curr = start;
+
+    loop {
+        if let Some(&prefix) = seen.get(&curr) {
+            let cycle = Cycle {
+                prefix,
+                len: states.len() - prefix,
+            };
+            return History { cycle, states };
+        }
+        let next = step(&curr);
+        seen.insert(curr.clone(), states.len());
+        states.push(curr);
+        curr = next;
+    }
+}
+
+#[test]
+fn brent_and_hashed_agree() {
+    let step = |&x: &u32| (x * x + 1) % 255;
+
+    for start in 0..255 {
+        let brent = brent(&start, step);
+        let history = hashed(start, step);
+        assert_eq!(brent, history.cycle, "from {start}");
+
+        let mut x = start;
+        for n in 0..300 {
+            assert_eq!(*history.state_at(n), x);
+            assert_eq!(brent.state_at(&start, step, n), x);
+            x = step(&x);
+        }
+    }
+
+    // 3 -> 10 -> 101 -> 2 -> 5 -> 26 -> 167 -> 95 -> 101
+    assert_eq!(brent(&3, step), Cycle { prefix: 2, len: 6 });
+    assert_eq!(hashed(0, |&x: &u32| x).cycle, Cycle { prefix: 0, len: 1 });
+}
@@ -0,0 +1,104 @@
+use std::io::{self, BufRead};
+
+use crate::params::Params;
+use crate::stream::{self, Streaming};
+use crate::{ParseError, Run, Solution};
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    const DAY: u32 = 1;
+    type Input<'a> = Vec<&'a str>;
+    type Answer1 = u32;
+    type Answer2 = u32;
+
+    fn parse(input: &str) -> Result<Vec<&str>, ParseError> {
+        Ok(input.lines().collect())
+    }
+
+    fn part1(lines: &Vec<&str>) -> u32 {
+        solve_with(lines, &part1_parse_digits)
+    }
+
+    fn part2(lines: &Vec<&str>) -> u32 {
+        solve_with(lines, &part2_parse_alpha_digits)
+    }
+
+    fn stream(input: &mut dyn BufRead, parts: &[u32], params: &Params) -> Option<io::Result<Run>> {
+        Some(stream::run::<Self>(input, parts, params))
+    }
+}
+
+impl Streaming for Day01 {
+    type Line<'a> = &'a str;
+    type Total1 = u32;
+    type Total2 = u32;
+
+    fn parse_line(line: &str) -> Result<&str, ParseError> {
+        Ok(line)
+    }
+
+    fn add1(total: &mut u32, line: &&str) {
+        *total += solve_with(&[line], &part1_parse_digits);
+    }
+
+    fn add2(total: &mut u32, line: &&str) {
+        *total += solve_with(&[line], &part2_parse_alpha_digits);
+    }
+
+    fn finish1(total: u32) -> u32 {
+        total
+    }
+
+    fn finish2(total: u32) -> u32 {
+        total
+    }
+}
+
+fn solve_with(
+    lines: &[&str],
+    parser: &'_ dyn Fn(&str) -> Box<dyn Iterator<Item = u32> + '_>,
+) -> u32 {
+    lines
+        .iter()
+        .map(|line| {
+            let mut digits = parser(line);
+            let first = digits.next().unwrap();
+            let last = digits.last().unwrap_or(first);
+            first * 10 + last
+        })
+        .sum()
+}
+
+fn part1_parse_digits(line: &str) -> Box<dyn Iterator<Item = u32> + '_> {
+    Box::new(line.chars().filter_map(|c| c.to_digit(10)))
+}
+
+fn part2_parse_alpha_digits(line: &str) -> Box<dyn Iterator<Item = u32> + '_> {
+    Box::new((0..line.len()).filter_map(|i| {
+        if let Some(n) = line[i..].chars().next().unwrap().to_digit(10) {
+            Some(n)
+        } else {
+            let mut j = i + 2;
+
+            while j < line.len() && j < i + 5 {
+                if let Some(n) = match line[i..=j].to_string().as_str() {
+                    "one" => Some(1),
+                    "two" => Some(2),
+                    "three" => Some(3),
+                    "four" => Some(4),
+                    "five" => Some(5),
+                    "six" => Some(6),
+                    "seven" => Some(7),
+                    "eight" => Some(8),
+                    "nine" => Some(9),
+                    _ => None,
+                } {
+                    return Some(n);
+                }
+                j += 1;
+            }
+            None
+        }
+    }))
+}
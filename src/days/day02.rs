@@ -0,0 +1,125 @@
+use std::collections::HashMap;
+use std::io::{self, BufRead};
+use std::slice;
+
+use crate::params::Params;
+use crate::parse::{self, ParseError};
+use crate::stream::{self, Streaming};
+use crate::{Run, Solution};
+
+pub struct Day02;
+
+impl Solution for Day02 {
+    const DAY: u32 = 2;
+    type Input<'a> = Vec<Game>;
+    type Answer1 = u32;
+    type Answer2 = u32;
+
+    fn parse(input: &str) -> Result<Vec<Game>, ParseError> {
+        input.lines().map(parse_line).collect()
+    }
+
+    fn part1(games: &Vec<Game>) -> u32 {
+        solve_with(games, part1)
+    }
+
+    fn part2(games: &Vec<Game>) -> u32 {
+        solve_with(games, part2)
+    }
+
+    fn stream(input: &mut dyn BufRead, parts: &[u32], params: &Params) -> Option<io::Result<Run>> {
+        Some(stream::run::<Self>(input, parts, params))
+    }
+}
+
+impl Streaming for Day02 {
+    type Line<'a> = Game;
+    type Total1 = u32;
+    type Total2 = u32;
+
+    fn parse_line(line: &str) -> Result<Game, ParseError> {
+        parse_line(line)
+    }
+
+    fn add1(total: &mut u32, game: &Game) {
+        *total += solve_with(slice::from_ref(game), part1);
+    }
+
+    fn add2(total: &mut u32, game: &Game) {
+        *total += solve_with(slice::from_ref(game), part2);
+    }
+
+    fn finish1(total: u32) -> u32 {
+        total
+    }
+
+    fn finish2(total: u32) -> u32 {
+        total
+    }
+}
+
+fn solve_with(games: &[Game], solver: impl Fn(&Game) -> Option<u32>) -> u32 {
+    games.iter().filter_map(solver).sum()
+}
+
+fn part1(game: &Game) -> Option<u32> {
+    const MAX_R: u32 = 12;
+    const MAX_G: u32 = 13;
+    const MAX_B: u32 = 14;
+
+    if game.r <= MAX_R && game.g <= MAX_G && game.b <= MAX_B {
+        Some(game.id)
+    } else {
+        None
+    }
+}
+
+fn part2(game: &Game) -> Option<u32> {
+    Some(game.r * game.g * game.b)
+}
+
+pub struct Game {
+    id: u32,
+    r: u32,
+    g: u32,
+    b: u32,
+}
+
+fn parse_line(line: &str) -> Result<Game, ParseError> {
+    let (id_str, draws_str) = parse::split_once(line, ": ")?;
+
+    let id = parse::number(id_str.split(' ').next_back().unwrap())?;
+
+    let draws = draws_str
+        .split("; ")
+        .map(|draw_str| {
+            draw_str
+                .split(", ")
+                .map(|draw| {
+                    let (count, color) = parse::split_once(draw, " ")?;
+                    match color {
+                        "red" | "green" | "blue" => {
+                            Ok((color.chars().next().unwrap(), parse::number(count)?))
+                        }
+                        _ => Err(ParseError::new(color, "`red`, `green` or `blue`")),
+                    }
+                })
+                .collect::<Result<HashMap<_, _>, _>>()
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let mut max_vals = ['r', 'g', 'b'].iter().map(|color| {
+        draws
+            .iter()
+            .map(|draw| *draw.get(color).unwrap_or(&0))
+            .max()
+            .unwrap()
+    });
+
+    Ok(Game {
+        id,
+        r: max_vals.next().unwrap(),
+        g: max_vals.next().unwrap(),
+        b: max_vals.next().unwrap(),
+    })
+}
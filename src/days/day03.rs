@@ -0,0 +1,90 @@
+use std::collections::VecDeque;
+
+use crate::grid::Grid;
+use crate::parse::{self, ParseError};
+use crate::Solution;
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    const DAY: u32 = 3;
+    type Input<'a> = Grid<char>;
+    type Answer1 = u32;
+    type Answer2 = u32;
+
+    fn parse(input: &str) -> Result<Grid<char>, ParseError> {
+        parse::grid(input, "a digit, `.` or a symbol", |c| {
+            c.is_ascii_graphic().then_some(c)
+        })
+    }
+
+    fn part1(grid: &Grid<char>) -> u32 {
+        solve_with(grid, &part1_symbol_predicate, &part1_agg_fn)
+    }
+
+    fn part2(grid: &Grid<char>) -> u32 {
+        solve_with(grid, &part2_symbol_predicate, &part2_agg_fn)
+    }
+}
+
+fn part1_symbol_predicate(c: &char) -> bool {
+    !c.is_ascii_digit() && *c != '.'
+}
+
+fn part1_agg_fn(numbers: Vec<u32>) -> Option<u32> {
+    Some(numbers.iter().sum())
+}
+
+fn part2_symbol_predicate(c: &char) -> bool {
+    *c == '*'
+}
+
+fn part2_agg_fn(numbers: Vec<u32>) -> Option<u32> {
+    if numbers.len() == 2 {
+        Some(numbers[0] * numbers[1])
+    } else {
+        None
+    }
+}
+
+fn solve_with(
+    grid: &Grid<char>,
+    symbol_pred: &impl Fn(&char) -> bool,
+    agg_fn: &impl Fn(Vec<u32>) -> Option<u32>,
+) -> u32 {
+    let m: usize = grid.cols();
+    let mut seen = Grid::new(grid.rows(), m, false);
+
+    grid.iter()
+        .filter_map(|(pos, elem)| symbol_pred(elem).then_some(pos))
+        .filter_map(|pos| {
+            let mut numbers = Vec::new();
+            for (r, c) in grid.neighbors8(pos) {
+                if !seen[(r, c)] && grid[(r, c)].is_ascii_digit() {
+                    let mut digits: VecDeque<_> =
+                        VecDeque::from([grid[(r, c)].to_digit(10).unwrap()]);
+                    seen[(r, c)] = true;
+
+                    // check left
+                    let mut left = 1;
+                    while c >= left && grid[(r, c - left)].is_ascii_digit() {
+                        digits.push_front(grid[(r, c - left)].to_digit(10).unwrap());
+                        seen[(r, c - left)] = true;
+                        left += 1;
+                    }
+
+                    // check right
+                    let mut right = 1;
+                    while c + right < m && grid[(r, c + right)].is_ascii_digit() {
+                        digits.push_back(grid[(r, c + right)].to_digit(10).unwrap());
+                        seen[(r, c + right)] = true;
+                        right += 1;
+                    }
+
+                    numbers.push(digits.iter().fold(0, |acc, x| acc * 10 + x));
+                }
+            }
+            agg_fn(numbers)
+        })
+        .sum()
+}
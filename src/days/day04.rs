@@ -0,0 +1,121 @@
+use std::collections::VecDeque;
+use std::io::{self, BufRead};
+
+use crate::params::Params;
+use crate::parse::{self, ParseError};
+use crate::stream::{self, Streaming};
+use crate::{Run, Solution};
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    const DAY: u32 = 4;
+    /// number of winning numbers on each card
+    type Input<'a> = Vec<u32>;
+    type Answer1 = u32;
+    type Answer2 = u32;
+
+    fn parse(input: &str) -> Result<Vec<u32>, ParseError> {
+        input.lines().map(count_matches).collect()
+    }
+
+    fn part1(counts: &Vec<u32>) -> u32 {
+        solve_with(counts, &part1_agg)
+    }
+
+    fn part2(counts: &Vec<u32>) -> u32 {
+        solve_with(counts, &part2_agg)
+    }
+
+    fn stream(input: &mut dyn BufRead, parts: &[u32], params: &Params) -> Option<io::Result<Run>> {
+        Some(stream::run::<Self>(input, parts, params))
+    }
+}
+
+impl Streaming for Day04 {
+    type Line<'a> = u32;
+    type Total1 = u32;
+    type Total2 = Copies;
+
+    fn parse_line(line: &str) -> Result<u32, ParseError> {
+        count_matches(line)
+    }
+
+    fn add1(total: &mut u32, &count: &u32) {
+        *total += solve_with(&[count], &part1_agg);
+    }
+
+    fn add2(copies: &mut Copies, &count: &u32) {
+        let held = 1 + copies.won.pop_front().unwrap_or(0);
+        copies.total += held;
+        for j in 0..count as usize {
+            match copies.won.get_mut(j) {
+                Some(won) => *won += held,
+                None => copies.won.push_back(held),
+            }
+        }
+    }
+
+    fn finish1(total: u32) -> u32 {
+        total
+    }
+
+    fn finish2(copies: Copies) -> u32 {
+        copies.total
+    }
+}
+
+/// Part 2 while streaming: the cards held so far, and the copies already
+/// won of the next few cards.
+#[derive(Default)]
+pub struct Copies {
+    total: u32,
+    won: VecDeque<u32>,
+}
+
+fn part1_agg(it: Box<dyn Iterator<Item = u32> + '_>) -> u32 {
+    it.filter(|n| *n > 0).map(|num| 2_u32.pow(num - 1)).sum()
+}
+
+fn part2_agg(it: Box<dyn Iterator<Item = u32> + '_>) -> u32 {
+    let counts = it.collect::<Vec<u32>>();
+    let mut copies = vec![1_u32; counts.len()];
+    for (i, &count) in counts.iter().enumerate() {
+        // copies of cards past the last are never held, as when streaming
+        let last = (i + count as usize).min(counts.len() - 1);
+        for j in i + 1..=last {
+            copies[j] += copies[i];
+        }
+    }
+    copies.iter().sum()
+}
+
+fn solve_with(counts: &[u32], agg: &'_ dyn Fn(Box<dyn Iterator<Item = u32> + '_>) -> u32) -> u32 {
+    agg(Box::new(counts.iter().copied()))
+}
+
+fn count_matches(line: &str) -> Result<u32, ParseError> {
+    let (_, nums) = parse::split_once(line, ":")?;
+    let (win_str, my_str) = parse::split_once(nums, "|")?;
+
+    let numbers = |nums_str: &str| {
+        nums_str
+            .split_whitespace()
+            .map(parse::number::<u32>)
+            .collect::<Result<Vec<_>, _>>()
+    };
+    let win_nums = numbers(win_str)?;
+
+    Ok(numbers(my_str)?
+        .iter()
+        .filter(|num| win_nums.contains(num))
+        .count() as u32)
+}
+
+#[test]
+fn copies_past_the_last_card() {
+    let input = "Card 1: 41 48 | 83 41\nCard 2: 13 32 20 | 61 13 32 20\n";
+    let counts = Day04::parse(input).unwrap();
+    assert_eq!(Day04::part2(&counts), 3);
+    assert_eq!(stream::answers::<Day04>(input).unwrap(), ["5", "3"]);
+}
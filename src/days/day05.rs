@@ -0,0 +1,208 @@
+use crate::interval::{Interval, IntervalSet, OffsetMap};
+use crate::parse::{self, ParseError};
+use crate::Solution;
+
+pub struct Day05;
+
+pub struct Almanac {
+    seeds: Vec<u64>,
+    maps: Vec<OffsetMap<u64>>,
+}
+
+impl Solution for Day05 {
+    const DAY: u32 = 5;
+    type Input<'a> = Almanac;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(input: &str) -> Result<Almanac, ParseError> {
+        let (seeds_str, maps_str) = parse::split_once(input, "\n")?;
+        let seeds = parse::split_once(seeds_str, ": ")?
+            .1
+            .split_whitespace()
+            .map(parse::number)
+            .collect::<Result<Vec<u64>, _>>()?;
+
+        let maps = maps_str
+            .trim_start_matches('\n')
+            .split("\n\n")
+            .map(|s| {
+                let (_, ranges) = parse::split_once(s, ":\n")?;
+                let pieces = ranges
+                    .lines()
+                    .map(|line| {
+                        let mut nums = line.split_whitespace();
+                        let mut next =
+                            || parse::next(&mut nums, line, "a number").and_then(parse::number);
+                        let (dst_start, src_start, length) = (next()?, next()?, next()?);
+                        Ok((Interval::from_len(src_start, length), dst_start))
+                    })
+                    .collect::<Result<Vec<_>, ParseError>>()?;
+                OffsetMap::new(pieces)
+                    .ok_or_else(|| ParseError::new(ranges, "non-overlapping source ranges"))
+            })
+            .collect::<Result<_, ParseError>>()?;
+
+        Ok(Almanac { seeds, maps })
+    }
+
+    fn part1(almanac: &Almanac) -> u64 {
+        solve_with(almanac, &part1_seeds_parser)
+    }
+
+    fn part2(almanac: &Almanac) -> u64 {
+        solve_with(almanac, &part2_seeds_parser)
+    }
+}
+
+/// turns the seed numbers into the set of seeds to plant
+type SeedsParser = dyn Fn(&[u64]) -> IntervalSet<u64>;
+
+fn part1_seeds_parser(seeds: &[u64]) -> IntervalSet<u64> {
+    seeds.iter().map(|&x| Interval::from_len(x, 1)).collect()
+}
+
+fn part2_seeds_parser(seeds: &[u64]) -> IntervalSet<u64> {
+    seeds
+        .chunks(2)
+        .map(|pair| Interval::from_len(pair[0], pair[1]))
+        .collect()
+}
+
+fn solve_with(almanac: &Almanac, seeds_parser: &'_ SeedsParser) -> u64 {
+    almanac
+        .maps
+        .iter()
+        .fold(seeds_parser(&almanac.seeds), |src, map| map.apply(&src))
+        .min()
+        .unwrap()
+}
+
+#[test]
+fn example_seeds_one_at_a_time() {
+    let example = crate::fixture::input::<Day05>("example");
+    let almanac = Day05::parse(&example).unwrap();
+
+    // every seed pushed through the maps one at a time
+    let brute_force = |seeds: IntervalSet<u64>| {
+        seeds
+            .iter()
+            .flat_map(|i| i.start..i.end)
+            .map(|seed| almanac.maps.iter().fold(seed, |x, map| map.get(x)))
+            .min()
+            .unwrap()
+    };
+    assert_eq!(brute_force(part1_seeds_parser(&almanac.seeds)), 35);
+    assert_eq!(brute_force(part2_seeds_parser(&almanac.seeds)), 46);
+}
+
+/// seed ranges as `(start, len)`, and maps of `(source start, len,
+/// destination start)` pieces
+#[cfg(test)]
+#[derive(Clone, Debug)]
+struct Case {
+    seeds: Vec<(u64, u64)>,
+    maps: Vec<Vec<(u64, u64, u64)>>,
+}
+
+#[cfg(test)]
+impl crate::prop::Shrink for Case {
+    /// fewer or shorter seed ranges, fewer maps or pieces, shorter pieces or
+    /// smaller numbers; pieces never grow, so they stay disjoint
+    fn shrink(&self) -> Vec<Self> {
+        let mut out = Vec::new();
+        let mut push = |change: &dyn Fn(&mut Case)| {
+            let mut case = self.clone();
+            change(&mut case);
+            out.push(case);
+        };
+        for i in 0..self.seeds.len() {
+            if self.seeds.len() > 1 {
+                push(&|c| {
+                    c.seeds.remove(i);
+                });
+            }
+            push(&|c| c.seeds[i].0 /= 2);
+            if self.seeds[i].1 > 1 {
+                push(&|c| c.seeds[i].1 = 1);
+                push(&|c| c.seeds[i].1 -= 1);
+            }
+        }
+        for (m, map) in self.maps.iter().enumerate() {
+            push(&|c| {
+                c.maps.remove(m);
+            });
+            for (p, &(_, len, dst)) in map.iter().enumerate() {
+                push(&|c| {
+                    c.maps[m].remove(p);
+                });
+                if len > 1 {
+                    push(&|c| c.maps[m][p].1 -= 1);
+                }
+                if dst > 0 {
+                    push(&|c| c.maps[m][p].2 /= 2);
+                }
+            }
+        }
+        out.retain(|case| case.seeds != self.seeds || case.maps != self.maps);
+        out
+    }
+}
+
+#[test]
+fn ranges_match_single_seeds() {
+    use crate::gen::Rng;
+
+    let random_case = |rng: &mut Rng| {
+        let seeds = (0..rng.range(1..=3))
+            .map(|_| (rng.range(0..=60) as u64, rng.range(1..=10) as u64))
+            .collect();
+        let maps = (0..rng.range(1..=4))
+            .map(|_| {
+                let (mut pieces, mut at) = (Vec::new(), 0);
+                while at < 60 {
+                    let (gap, len) = (rng.range(0..=5) as u64, rng.range(1..=12) as u64);
+                    pieces.push((at + gap, len, rng.range(0..=60) as u64));
+                    at += gap + len;
+                }
+                rng.shuffle(&mut pieces);
+                pieces.truncate(rng.range(0..=pieces.len()));
+                pieces
+            })
+            .collect();
+        Case { seeds, maps }
+    };
+    let almanac = |case: &Case| Almanac {
+        seeds: case
+            .seeds
+            .iter()
+            .flat_map(|&(start, len)| [start, len])
+            .collect(),
+        maps: case
+            .maps
+            .iter()
+            .map(|pieces| {
+                let pieces = pieces
+                    .iter()
+                    .map(|&(src, len, dst)| (Interval::from_len(src, len), dst))
+                    .collect();
+                OffsetMap::new(pieces).unwrap()
+            })
+            .collect(),
+    };
+
+    crate::prop::agree(
+        500,
+        random_case,
+        |case| solve_with(&almanac(case), &part2_seeds_parser),
+        |case| {
+            let almanac = almanac(case);
+            case.seeds
+                .iter()
+                .flat_map(|&(start, len)| start..start + len)
+                .map(|seed| almanac.maps.iter().fold(seed, |x, map| map.get(x)))
+                .min()
+                .unwrap()
+        },
+    );
+}
@@ -0,0 +1,60 @@
+use crate::parse::{self, ParseError};
+use crate::Solution;
+
+pub struct Day06;
+
+/// `(time, record distance)` of each race as part 1 reads the sheet, and of
+/// the one race part 2 reads by ignoring the spaces
+type Races = (Vec<(u64, u64)>, (u64, u64));
+
+impl Solution for Day06 {
+    const DAY: u32 = 6;
+    type Input<'a> = Races;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(input: &str) -> Result<Races, ParseError> {
+        let mut lines = input.lines();
+        let mut row = |label: &str| {
+            let expected = format!("`{label}`");
+            let line = parse::next(&mut lines, input, &expected)?;
+            let mut tokens = line.split_whitespace();
+            let head = parse::next(&mut tokens, line, &expected)?;
+            if head != label {
+                return Err(ParseError::new(head, expected));
+            }
+            let tokens = tokens.collect::<Vec<_>>();
+            let numbers = tokens
+                .iter()
+                .map(|token| parse::number::<u64>(token))
+                .collect::<Result<Vec<_>, _>>()?;
+            let joined = tokens.concat().parse::<u64>().map_err(|_| {
+                ParseError::new(&line[head.len()..], "a number once the spaces are removed")
+            })?;
+            Ok((line, numbers, joined))
+        };
+        let (_, times, time) = row("Time:")?;
+        let (line, distances, distance) = row("Distance:")?;
+        if distances.len() != times.len() {
+            let expected = format!("{} distances, one per time", times.len());
+            return Err(ParseError::new(line, expected));
+        }
+        Ok((times.into_iter().zip(distances).collect(), (time, distance)))
+    }
+
+    fn part1((races, _): &Races) -> u64 {
+        races.iter().copied().map(compute_one).product()
+    }
+
+    fn part2(&(_, race): &Races) -> u64 {
+        compute_one(race)
+    }
+}
+
+fn compute_one((t, d): (u64, u64)) -> u64 {
+    let mut i = 1;
+    while i * (t - i) <= d {
+        i += 1;
+    }
+    t - i - i + 1
+}
@@ -1,14 +1,33 @@
 use std::collections::HashMap;
+use std::io::{self, BufRead};
 
 use itertools::Itertools;
 
+use crate::params::Params;
+use crate::parse::{self, ParseError};
+use crate::stream::{self, Streaming};
+use crate::{Run, Solution};
+
 #[derive(Debug, PartialEq, Eq)]
-struct Hand {
+pub struct Hand {
     cards: Vec<u8>,
     hand_type: HandType,
     bid: u64,
 }
 
+impl Hand {
+    fn new(cards: &str, bid: u64, char_mapper: &dyn Fn(char) -> u8) -> Self {
+        let cards = cards.chars().map(char_mapper).collect::<Vec<_>>();
+        let hand_type = HandType::new(&cards);
+
+        Hand {
+            cards,
+            hand_type,
+            bid,
+        }
+    }
+}
+
 impl PartialOrd for Hand {
     fn partial_cmp(&self, other: &Hand) -> Option<std::cmp::Ordering> {
         Some(self.cmp(other))
@@ -67,12 +86,70 @@ impl HandType {
     }
 }
 
-fn main() {
-    let input: &str = include_str!("../../inputs/day07.txt");
-    println!("Part1: {}", solve_with(input, &part1_char_mapper));
-    println!("Part2: {}", solve_with(input, &part2_char_mapper));
+pub struct Day07;
+
+impl Solution for Day07 {
+    const DAY: u32 = 7;
+    /// `(cards, bid)` per line; card values depend on the part
+    type Input<'a> = Vec<(&'a str, u64)>;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(input: &str) -> Result<Vec<(&str, u64)>, ParseError> {
+        input.lines().map(parse_hand).collect()
+    }
+
+    fn part1(hands: &Vec<(&str, u64)>) -> u64 {
+        solve_with(hands, &part1_char_mapper)
+    }
+
+    fn part2(hands: &Vec<(&str, u64)>) -> u64 {
+        solve_with(hands, &part2_char_mapper)
+    }
+
+    fn stream(input: &mut dyn BufRead, parts: &[u32], params: &Params) -> Option<io::Result<Run>> {
+        Some(stream::run::<Self>(input, parts, params))
+    }
+}
+
+/// Ranking needs every hand, so streaming keeps them, though only as cards
+/// and bid rather than the input text.
+impl Streaming for Day07 {
+    type Line<'a> = (&'a str, u64);
+    type Total1 = Vec<Hand>;
+    type Total2 = Vec<Hand>;
+
+    fn parse_line(line: &str) -> Result<(&str, u64), ParseError> {
+        parse_hand(line)
+    }
+
+    fn add1(hands: &mut Vec<Hand>, &(cards, bid): &(&str, u64)) {
+        hands.push(Hand::new(cards, bid, &part1_char_mapper));
+    }
+
+    fn add2(hands: &mut Vec<Hand>, &(cards, bid): &(&str, u64)) {
+        hands.push(Hand::new(cards, bid, &part2_char_mapper));
+    }
+
+    fn finish1(hands: Vec<Hand>) -> u64 {
+        winnings(hands)
+    }
+
+    fn finish2(hands: Vec<Hand>) -> u64 {
+        winnings(hands)
+    }
+}
+
+fn parse_hand(line: &str) -> Result<(&str, u64), ParseError> {
+    let (cards, bid) = parse::split_once(line, " ")?;
+    if cards.len() != 5 || !cards.chars().all(|c| CARDS.contains(c)) {
+        return Err(ParseError::new(cards, format!("5 cards from `{CARDS}`")));
+    }
+    Ok((cards, parse::number(bid)?))
 }
 
+const CARDS: &str = "23456789TJQKA";
+
 fn part1_char_mapper(c: char) -> u8 {
     match c {
         'T' => 10,
@@ -95,26 +172,17 @@ fn part2_char_mapper(c: char) -> u8 {
     }
 }
 
-fn solve_with<'a>(input: &'a str, char_mapper: &dyn Fn(char) -> u8) -> u64 {
-    input
-        .lines()
-        .map(|line: &'a str| {
-            let mut splits = line.splitn(2, ' ');
+fn solve_with(hands: &[(&str, u64)], char_mapper: &dyn Fn(char) -> u8) -> u64 {
+    winnings(
+        hands
+            .iter()
+            .map(|&(cards, bid)| Hand::new(cards, bid, char_mapper)),
+    )
+}
 
-            let cards = splits
-                .next()
-                .unwrap()
-                .chars()
-                .map(char_mapper)
-                .collect::<Vec<_>>();
-            let hand_type = HandType::new(&cards);
-
-            Hand {
-                cards,
-                hand_type,
-                bid: splits.next().unwrap().parse().unwrap(),
-            }
-        })
+fn winnings(hands: impl IntoIterator<Item = Hand>) -> u64 {
+    hands
+        .into_iter()
         .sorted()
         .enumerate()
         .map(|(i, h)| (i as u64 + 1) * h.bid)
@@ -149,21 +217,3 @@ fn test_part2_parse_handtype() {
     assert_eq!(helper("28J28"), H::FullHouse);
     assert_eq!(helper("424KT"), H::OnePair);
 }
-
-#[test]
-fn example() {
-    let example: &str = "32T3K 765
-T55J5 684
-KK677 28
-KTJJT 220
-QQQJA 483";
-    assert_eq!(solve_with(example, &part1_char_mapper), 6440);
-    assert_eq!(solve_with(example, &part2_char_mapper), 5905);
-}
-
-#[test]
-fn answer() {
-    let input: &str = include_str!("../../inputs/day07.txt");
-    assert_eq!(solve_with(input, &part1_char_mapper), 253933213);
-    assert_eq!(solve_with(input, &part2_char_mapper), 253473930);
-}
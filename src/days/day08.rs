@@ -0,0 +1,234 @@
+use std::collections::HashMap;
+
+use itertools::Itertools;
+
+use crate::math::{self, Progression};
+use crate::parse::{self, ParseError};
+use crate::{Assumption, Solution};
+
+type Network<'a> = (Vec<char>, HashMap<&'a str, (&'a str, &'a str)>);
+
+fn parse(input: &str) -> Result<Network<'_>, ParseError> {
+    let (instructions_str, nodes_str) = parse::split_once(input, "\n\n")?;
+
+    if instructions_str.is_empty() {
+        return Err(ParseError::new(instructions_str, "`L` or `R`"));
+    }
+    if let Some(i) = instructions_str.find(|c| c != 'L' && c != 'R') {
+        return Err(ParseError::new(&instructions_str[i..i + 1], "`L` or `R`"));
+    }
+    let instructions = instructions_str.chars().collect::<Vec<char>>();
+
+    let lines = nodes_str
+        .lines()
+        .map(|line| {
+            let (name, children) = parse::split_once(line, " = ")?;
+            let children = children
+                .strip_prefix('(')
+                .and_then(|s| s.strip_suffix(')'))
+                .ok_or_else(|| ParseError::new(children, "`(left, right)`"))?;
+            Ok((name, parse::split_once(children, ", ")?))
+        })
+        .collect::<Result<Vec<_>, ParseError>>()?;
+    let nodes = lines.iter().copied().collect::<HashMap<_, _>>();
+
+    // in input order, so the first undefined child is the one reported
+    for (_, (left, right)) in lines {
+        if let Some(child) = [left, right].into_iter().find(|c| !nodes.contains_key(c)) {
+            return Err(ParseError::new(child, "a node defined below"));
+        }
+    }
+
+    Ok((instructions, nodes))
+}
+
+fn traverse(
+    instructions: &[char],
+    nodes: &HashMap<&str, (&str, &str)>,
+    start: &str,
+    stop_pred: Box<dyn Fn(&str) -> bool>,
+) -> u64 {
+    let mut curr = start;
+    let mut count = 0;
+
+    // `parse` made sure every child is a node
+    for &turn in instructions.iter().cycle() {
+        if stop_pred(curr) {
+            break;
+        }
+        let (left, right) = nodes[curr];
+        curr = if turn == 'L' { left } else { right };
+        count += 1;
+    }
+
+    count
+}
+
+pub struct Day08;
+
+impl Solution for Day08 {
+    const DAY: u32 = 8;
+    type Input<'a> = Network<'a>;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(input: &str) -> Result<Network<'_>, ParseError> {
+        parse(input)
+    }
+
+    fn part1(network: &Network) -> u64 {
+        part1(network)
+    }
+
+    fn part2(network: &Network) -> u64 {
+        part2(network)
+    }
+
+    fn assumptions(network: &Network) -> Vec<Assumption> {
+        assumptions(network)
+    }
+}
+
+fn assumptions((instructions, nodes): &Network) -> Vec<Assumption> {
+    let reaches_zzz = nodes.contains_key("AAA")
+        && !hits(instructions, nodes, "AAA", |name| name == "ZZZ").is_empty();
+    let mut checks = vec![Assumption::new(
+        "the walk from AAA reaches ZZZ",
+        reaches_zzz,
+        format!("AAA defined: {}", nodes.contains_key("AAA")),
+    )];
+
+    // the solution copes with offset cycles, but the classic lcm shortcut
+    // relies on every ghost's only Z being exactly one loop from its start
+    let hits = nodes
+        .keys()
+        .filter(|name| name.ends_with('A'))
+        .sorted()
+        .map(|name| (*name, hits(instructions, nodes, name, is_z)))
+        .collect::<Vec<_>>();
+    let stuck = hits
+        .iter()
+        .filter(|(_, h)| h.is_empty())
+        .map(|(name, _)| *name)
+        .collect::<Vec<_>>();
+    checks.push(Assumption::new(
+        "every ghost reaches a ..Z node",
+        stuck.is_empty(),
+        format!("never reaching one: {stuck:?}"),
+    ));
+
+    let aligned = hits
+        .iter()
+        .all(|(_, h)| matches!(h[..], [p] if p.offset == p.period));
+    let shapes = hits
+        .iter()
+        .map(|(name, h)| {
+            let h = h.iter().map(|p| format!("{}+{}k", p.offset, p.period));
+            format!("{name}: {}", h.collect::<Vec<_>>().join(" | "))
+        })
+        .collect::<Vec<_>>();
+    checks.push(Assumption::new(
+        "each ghost's cycle is aligned (one ..Z, hit every L steps from 0)",
+        aligned,
+        shapes.join(", "),
+    ));
+
+    checks
+}
+
+fn part1((instructions, nodes): &Network) -> u64 {
+    traverse(instructions, nodes, "AAA", Box::new(|name| name == "ZZZ"))
+}
+
+fn part2((instructions, nodes): &Network) -> u64 {
+    nodes
+        .keys()
+        .filter(|name| name.ends_with('A'))
+        .map(|name| hits(instructions, nodes, name, is_z))
+        .multi_cartesian_product()
+        .filter_map(math::combine)
+        .map(|p| p.offset)
+        .min()
+        .unwrap()
+}
+
+fn is_z(name: &str) -> bool {
+    name.ends_with('Z')
+}
+
+/// Every step count at which the walk from `start` is on an `is_end` node,
+/// as one progression per visit: those before the walk starts repeating
+/// happen once, those inside the loop recur with its length.
+fn hits(
+    instructions: &[char],
+    nodes: &HashMap<&str, (&str, &str)>,
+    start: &str,
+    is_end: fn(&str) -> bool,
+) -> Vec<Progression> {
+    // first step each (node, instruction index) was seen at
+    let mut seen = HashMap::new();
+    let mut curr = start;
+
+    for step in 0.. {
+        let i = step % instructions.len();
+        if let Some(&loop_start) = seen.get(&(curr, i)) {
+            let period = step - loop_start;
+            return seen
+                .into_iter()
+                .filter(|((name, _), _)| is_end(name))
+                .map(|(_, t)| {
+                    let period = if t >= loop_start { period } else { 0 };
+                    Progression::new(t as u64, period as u64)
+                })
+                .sorted_by_key(|p| p.offset)
+                .collect();
+        }
+        seen.insert((curr, i), step);
+
+        let (left, right) = nodes[curr];
+        curr = if instructions[i] == 'L' { left } else { right };
+    }
+
+    unreachable!()
+}
+
+#[test]
+fn offset_cycles() {
+    // 11A is on a Z every 2 steps from step 2, 22A every 3 from step 5, so
+    // the lcm of the first hits (10) would be wrong
+    let example: &str = "L
+
+11A = (11B, 11B)
+11B = (11Z, 11Z)
+11Z = (11C, 11C)
+11C = (11Z, 11Z)
+22A = (22B, 22B)
+22B = (22C, 22C)
+22C = (22D, 22D)
+22D = (22E, 22E)
+22E = (22Z, 22Z)
+22Z = (22F, 22F)
+22F = (22G, 22G)
+22G = (22Z, 22Z)";
+    assert_eq!(part2(&parse(example).unwrap()), 8);
+
+    let checks = assumptions(&parse(example).unwrap());
+    let holds = checks.iter().map(|a| a.holds).collect::<Vec<_>>();
+    assert_eq!(holds, [false, true, false]);
+    assert_eq!(checks[2].detail, "11A: 2+2k, 22A: 5+3k");
+}
+
+#[test]
+fn rejects_bad_networks() {
+    let at = |input| {
+        let err = parse(input).unwrap_err().locate(8, input);
+        (err.line, err.column, err.found)
+    };
+    let (line, column, _) = at("\n\nAAA = (AAA, AAA)");
+    assert_eq!((line, column), (1, 1));
+    assert_eq!(at("LRX\n\nAAA = (AAA, AAA)"), (1, 3, "X".to_string()));
+    assert_eq!(
+        at("L\n\nAAA = (BBB, ZZZ)\nBBB = (AAA, CCC)\nZZZ = (ZZZ, ZZZ)"),
+        (4, 13, "CCC".to_string())
+    );
+}
@@ -0,0 +1,94 @@
+use std::io::{self, BufRead};
+use std::slice;
+
+use crate::params::Params;
+use crate::parse::{self, ParseError};
+use crate::stream::{self, Streaming};
+use crate::{Run, Solution};
+
+pub struct Day09;
+
+impl Solution for Day09 {
+    const DAY: u32 = 9;
+    type Input<'a> = Vec<Vec<i64>>;
+    type Answer1 = i64;
+    type Answer2 = i64;
+
+    fn parse(input: &str) -> Result<Vec<Vec<i64>>, ParseError> {
+        input.lines().map(parse_sequence).collect()
+    }
+
+    fn part1(sequences: &Vec<Vec<i64>>) -> i64 {
+        solve_with(sequences, &part1_compute)
+    }
+
+    fn part2(sequences: &Vec<Vec<i64>>) -> i64 {
+        solve_with(sequences, &part2_compute)
+    }
+
+    fn stream(input: &mut dyn BufRead, parts: &[u32], params: &Params) -> Option<io::Result<Run>> {
+        Some(stream::run::<Self>(input, parts, params))
+    }
+}
+
+impl Streaming for Day09 {
+    type Line<'a> = Vec<i64>;
+    type Total1 = i64;
+    type Total2 = i64;
+
+    fn parse_line(line: &str) -> Result<Vec<i64>, ParseError> {
+        parse_sequence(line)
+    }
+
+    fn add1(total: &mut i64, nums: &Vec<i64>) {
+        *total += solve_with(slice::from_ref(nums), &part1_compute);
+    }
+
+    fn add2(total: &mut i64, nums: &Vec<i64>) {
+        *total += solve_with(slice::from_ref(nums), &part2_compute);
+    }
+
+    fn finish1(total: i64) -> i64 {
+        total
+    }
+
+    fn finish2(total: i64) -> i64 {
+        total
+    }
+}
+
+fn parse_sequence(line: &str) -> Result<Vec<i64>, ParseError> {
+    line.split_whitespace().map(parse::number).collect()
+}
+
+fn solve_with(sequences: &[Vec<i64>], solver: &dyn Fn(Vec<Vec<i64>>) -> i64) -> i64 {
+    sequences
+        .iter()
+        .map(|nums| {
+            let mut history = vec![nums.clone()]; // using as stack
+            loop {
+                let curr = history.last().unwrap();
+                let diff = (1..curr.len())
+                    .map(|i| curr[i] - curr[i - 1])
+                    .collect::<Vec<_>>();
+                if diff.iter().any(|&x| x != 0) {
+                    history.push(diff);
+                } else {
+                    break history;
+                }
+            }
+        })
+        .map(solver)
+        .sum()
+}
+
+fn part1_compute(history: Vec<Vec<i64>>) -> i64 {
+    history
+        .iter()
+        .rev()
+        .fold(0, |acc, v| acc + v.last().unwrap())
+}
+
+fn part2_compute(history: Vec<Vec<i64>>) -> i64 {
+    history.iter().rev().fold(0, |acc, v| v[0] - acc)
+}
@@ -0,0 +1,266 @@
+use std::io;
+
+use crate::graph;
+use crate::grid::{Grid, Pos};
+use crate::parse::{self, ParseError};
+use crate::render::{self, Renderer, Rgb};
+use crate::{Assumption, Solution};
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    const DAY: u32 = 10;
+    /// the tiles and where the `S` tile is
+    type Input<'a> = (Grid<char>, Pos);
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(input: &str) -> Result<(Grid<char>, Pos), ParseError> {
+        to_grid(input)
+    }
+
+    fn part1((grid, start): &(Grid<char>, Pos)) -> u64 {
+        part1(grid, *start)
+    }
+
+    fn part2((grid, start): &(Grid<char>, Pos)) -> u64 {
+        part2(grid, *start)
+    }
+
+    fn assumptions((grid, start): &(Grid<char>, Pos)) -> Vec<Assumption> {
+        assumptions(grid, *start)
+    }
+
+    fn render((grid, start): &(Grid<char>, Pos), out: &mut Renderer) -> io::Result<()> {
+        render(grid, *start, out)
+    }
+}
+
+/// the loop in yellow with the tiles it encloses shaded green
+fn render(grid: &Grid<char>, start: Pos, out: &mut Renderer) -> io::Result<()> {
+    let is_route = route(grid, start);
+    let inside = enclosed(grid, start);
+    let image = render::image(grid, |pos, &ch| match ch {
+        _ if is_route[pos] => Rgb::YELLOW,
+        _ if inside[pos] => Rgb::GREEN,
+        '.' => Rgb::DARK,
+        _ => Rgb::GREY,
+    });
+    out.image("loop", &image)
+}
+
+fn assumptions(grid: &Grid<char>, start: Pos) -> Vec<Assumption> {
+    let joined = links(grid, start).count();
+    vec![Assumption::new(
+        "the start tile joins exactly two pipes",
+        joined == 2,
+        format!("joins {joined}"),
+    )]
+}
+
+fn to_grid(input: &str) -> Result<(Grid<char>, Pos), ParseError> {
+    let grid = parse::char_grid(input, "|-LJ7F.S")?;
+    let start = grid
+        .find(&'S')
+        .ok_or_else(|| ParseError::new(&input[input.len()..], "a start tile `S`"))?;
+
+    Ok((grid, start))
+}
+
+fn part1(grid: &Grid<char>, start: Pos) -> u64 {
+    let loop_len = graph::bfs(start, |&pos| links(grid, pos)).len();
+    // the farthest point is halfway round
+    (loop_len / 2) as u64
+}
+
+fn part2(grid: &Grid<char>, start: Pos) -> u64 {
+    let inside = enclosed(grid, start);
+    inside.iter().filter(|(_, &enclosed)| enclosed).count() as u64
+}
+
+/// tiles inside the loop through `start`
+fn enclosed(grid: &Grid<char>, start: Pos) -> Grid<bool> {
+    let mut grid = grid.clone();
+
+    let is_route = route(&grid, start);
+
+    // replace 'S' for easier proceesing
+    grid[start] = {
+        let facing = |d, dir| {
+            grid.offset(start, d)
+                .is_some_and(|p| is_facing(dir, grid[p]))
+        };
+        let north = facing((-1, 0), 'S');
+        let south = facing((1, 0), 'N');
+        let east = facing((0, 1), 'W');
+        let west = facing((0, -1), 'E');
+
+        match (north, south, east, west) {
+            (true, true, false, false) => '|',
+            (false, false, true, true) => '-',
+            (true, false, true, false) => 'L',
+            (true, false, false, true) => 'J',
+            (false, true, true, false) => 'F',
+            (false, true, false, true) => '7',
+            _ => unreachable!(),
+        }
+    };
+
+    let mut inside = Grid::new(grid.rows(), grid.cols(), false);
+    for pos in grid.positions() {
+        inside[pos] = !is_route[pos] && is_enclosed_by(&is_route, &grid, pos);
+    }
+    inside
+}
+
+fn is_enclosed_by(is_route: &Grid<bool>, grid: &Grid<char>, (r, c): Pos) -> bool {
+    let north = {
+        let mut edge_count = 0;
+        let mut prev_corner = None;
+
+        for i in (0..r).rev() {
+            if is_route[(i, c)] {
+                match grid[(i, c)] {
+                    ch if ch == 'L' || ch == 'J' => {
+                        prev_corner = Some(ch);
+                    }
+                    '7' => {
+                        if let Some('L') = prev_corner.take() {
+                            edge_count += 1;
+                        }
+                    }
+                    'F' => {
+                        if let Some('J') = prev_corner.take() {
+                            edge_count += 1;
+                        }
+                    }
+                    '-' => edge_count += 1,
+                    _ => {}
+                }
+            }
+        }
+        edge_count > 0 && edge_count % 2 == 1
+    };
+
+    let south = {
+        let mut edge_count = 0;
+        let mut prev_corner = None;
+        for i in (r + 1)..is_route.rows() {
+            if is_route[(i, c)] {
+                match grid[(i, c)] {
+                    ch if ch == '7' || ch == 'F' => {
+                        prev_corner = Some(ch);
+                    }
+                    'L' => {
+                        if let Some('7') = prev_corner.take() {
+                            edge_count += 1;
+                        }
+                    }
+                    'J' => {
+                        if let Some('F') = prev_corner.take() {
+                            edge_count += 1;
+                        }
+                    }
+                    '-' => edge_count += 1,
+                    _ => {}
+                }
+            }
+        }
+        edge_count > 0 && edge_count % 2 == 1
+    };
+
+    let west = {
+        let mut edge_count = 0;
+        let mut prev_corner = None;
+        for j in (0..c).rev() {
+            if is_route[(r, j)] {
+                match grid[(r, j)] {
+                    ch if ch == '7' || ch == 'J' => {
+                        prev_corner = Some(ch);
+                    }
+                    'L' => {
+                        if let Some('7') = prev_corner.take() {
+                            edge_count += 1;
+                        }
+                    }
+                    'F' => {
+                        if let Some('J') = prev_corner.take() {
+                            edge_count += 1;
+                        }
+                    }
+                    '|' => edge_count += 1,
+                    _ => {}
+                }
+            }
+        }
+        edge_count > 0 && edge_count % 2 == 1
+    };
+
+    let east = {
+        let mut edge_count = 0;
+        let mut prev_corner = None;
+        for j in (c + 1)..is_route.cols() {
+            if is_route[(r, j)] {
+                match grid[(r, j)] {
+                    ch if ch == 'L' || ch == 'F' => {
+                        prev_corner = Some(ch);
+                    }
+                    '7' => {
+                        if let Some('L') = prev_corner.take() {
+                            edge_count += 1;
+                        }
+                    }
+                    'J' => {
+                        if let Some('F') = prev_corner.take() {
+                            edge_count += 1;
+                        }
+                    }
+                    '|' => edge_count += 1,
+                    _ => {}
+                }
+            }
+        }
+        edge_count > 0 && edge_count % 2 == 1
+    };
+
+    north && south && west && east
+}
+
+fn is_facing(dir: char, ch: char) -> bool {
+    if ch == 'S' {
+        return true;
+    }
+    match dir {
+        'N' => ch == '|' || ch == 'L' || ch == 'J',
+        'S' => ch == '|' || ch == '7' || ch == 'F',
+        'E' => ch == '-' || ch == 'L' || ch == 'F',
+        'W' => ch == '-' || ch == 'J' || ch == '7',
+        _ => unreachable!(),
+    }
+}
+
+/// `is_facing` direction out of a pipe, the direction back, and the step
+const LINKS: [(char, char, (isize, isize)); 4] = [
+    ('N', 'S', (-1, 0)),
+    ('S', 'N', (1, 0)),
+    ('E', 'W', (0, 1)),
+    ('W', 'E', (0, -1)),
+];
+
+/// neighbours whose pipes connect to the one at `pos`
+fn links(grid: &Grid<char>, pos: Pos) -> impl Iterator<Item = Pos> + '_ {
+    LINKS.iter().filter_map(move |&(out, back, d)| {
+        let next = grid.offset(pos, d)?;
+        (is_facing(out, grid[pos]) && is_facing(back, grid[next])).then_some(next)
+    })
+}
+
+/// tiles of the loop through `start`, which is assumed to connect to
+/// exactly two pipes so that nothing else is reachable from it
+fn route(grid: &Grid<char>, start: Pos) -> Grid<bool> {
+    let mut is_route = Grid::new(grid.rows(), grid.cols(), false);
+    for (&pos, _) in graph::bfs(start, |&pos| links(grid, pos)).iter() {
+        is_route[pos] = true;
+    }
+    is_route
+}
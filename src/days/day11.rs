@@ -0,0 +1,118 @@
+use crate::grid::{Grid, Pos};
+use crate::params::{Param, Params};
+use crate::parse::{self, ParseError};
+use crate::Solution;
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    const DAY: u32 = 11;
+    type Input<'a> = Grid<char>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+    const PARAMS: &'static [Param] = &[Param::new(
+        "expansion",
+        "how many rows or columns each empty one becomes",
+    )
+    .part1("2")
+    .part2("1000000")];
+
+    fn parse(input: &str) -> Result<Grid<char>, ParseError> {
+        parse::char_grid(input, ".#")
+    }
+
+    fn part1(grid: &Grid<char>) -> usize {
+        Self::part1_with(grid, &Params::defaults(Self::PARAMS, 1))
+    }
+
+    fn part2(grid: &Grid<char>) -> usize {
+        Self::part2_with(grid, &Params::defaults(Self::PARAMS, 2))
+    }
+
+    fn part1_with(grid: &Grid<char>, params: &Params) -> usize {
+        solve(grid, expansion(params))
+    }
+
+    fn part2_with(grid: &Grid<char>, params: &Params) -> usize {
+        solve(grid, expansion(params))
+    }
+}
+
+/// an empty row can grow, but not vanish
+fn expansion(params: &Params) -> usize {
+    params.get_valid("expansion", |&factor| factor >= 1)
+}
+
+fn solve(grid: &Grid<char>, expansion_factor: usize) -> usize {
+    let (rows_to_expand, cols_to_expand) = expand(grid);
+
+    let galaxies = grid
+        .iter()
+        .filter_map(|(pos, ch)| (*ch == '#').then_some(pos))
+        .collect::<Vec<Pos>>();
+
+    let mut sum = 0;
+
+    for i in 0..galaxies.len() {
+        for j in i..galaxies.len() {
+            // r2 > r1
+            let (r1, r2) = if galaxies[i].0 < galaxies[j].0 {
+                (galaxies[i].0, galaxies[j].0)
+            } else {
+                (galaxies[j].0, galaxies[i].0)
+            };
+
+            // c2 > c1
+            let (c1, c2) = if galaxies[i].1 < galaxies[j].1 {
+                (galaxies[i].1, galaxies[j].1)
+            } else {
+                (galaxies[j].1, galaxies[i].1)
+            };
+
+            let x = r2 - r1
+                + ((r1..r2).filter(|r| rows_to_expand.contains(r)).count()
+                    * (expansion_factor - 1));
+            let y = c2 - c1
+                + ((c1..c2).filter(|c| cols_to_expand.contains(c)).count()
+                    * (expansion_factor - 1));
+
+            sum += x + y;
+        }
+    }
+
+    sum
+}
+
+fn expand(grid: &Grid<char>) -> (Vec<usize>, Vec<usize>) {
+    let rows_to_expand = grid
+        .iter_rows()
+        .enumerate()
+        .filter_map(|(i, row)| {
+            if row.iter().all(|&ch| ch == '.') {
+                Some(i)
+            } else {
+                None
+            }
+        })
+        .collect::<Vec<usize>>();
+
+    let cols_to_expand = (0..grid.cols())
+        .filter(|&c| grid.column(c).all(|&ch| ch == '.'))
+        .collect::<Vec<usize>>();
+
+    (rows_to_expand, cols_to_expand)
+}
+
+#[test]
+fn expansion_of_at_least_one() {
+    let grid = Day11::parse("#.\n..\n.#").unwrap();
+    let with = |factor| {
+        let mut params = Params::default();
+        params.set("expansion", factor);
+        std::panic::catch_unwind(|| Day11::part1_with(&grid, &params))
+    };
+    assert_eq!(with("1").unwrap(), 3);
+    assert_eq!(with("2").unwrap(), 4);
+    let bad = with("0").unwrap_err();
+    assert_eq!(*bad.downcast::<String>().unwrap(), "invalid expansion `0`");
+}
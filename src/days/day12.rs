@@ -0,0 +1,348 @@
+use std::collections::HashMap;
+use std::io::{self, BufRead};
+
+use crate::par;
+use crate::params::{Param, Params};
+use crate::parse::{self, ParseError};
+use crate::stats;
+use crate::stream::{self, Streaming};
+use crate::{Run, Solution};
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    const DAY: u32 = 12;
+    type Input<'a> = Vec<Row>;
+    type Answer1 = u64;
+    type Answer2 = u64;
+    const PARAMS: &'static [Param] =
+        &[
+            Param::new("unfold", "how many copies of each row are joined into one")
+                .part1("1")
+                .part2("5"),
+        ];
+
+    fn parse(input: &str) -> Result<Vec<Row>, ParseError> {
+        parse(input)
+    }
+
+    fn part1(rows: &Vec<Row>) -> u64 {
+        Self::part1_with(rows, &Params::defaults(Self::PARAMS, 1))
+    }
+
+    fn part2(rows: &Vec<Row>) -> u64 {
+        Self::part2_with(rows, &Params::defaults(Self::PARAMS, 2))
+    }
+
+    fn part1_with(rows: &Vec<Row>, params: &Params) -> u64 {
+        total(rows, params.get("unfold"))
+    }
+
+    fn part2_with(rows: &Vec<Row>, params: &Params) -> u64 {
+        total(rows, params.get("unfold"))
+    }
+
+    fn stream(input: &mut dyn BufRead, parts: &[u32], params: &Params) -> Option<io::Result<Run>> {
+        Some(stream::run::<Self>(input, parts, params))
+    }
+}
+
+/// One row at a time, so `--threads` has nothing to split.
+impl Streaming for Day12 {
+    type Line<'a> = Row;
+    type Total1 = Unfolded;
+    type Total2 = Unfolded;
+
+    fn parse_line(line: &str) -> Result<Row, ParseError> {
+        parse_row(line)
+    }
+
+    fn start1(params: &Params) -> Unfolded {
+        Unfolded::new(params)
+    }
+
+    fn start2(params: &Params) -> Unfolded {
+        Unfolded::new(params)
+    }
+
+    fn add1(total: &mut Unfolded, row: &Row) {
+        total.add(row);
+    }
+
+    fn add2(total: &mut Unfolded, row: &Row) {
+        total.add(row);
+    }
+
+    fn finish1(total: Unfolded) -> u64 {
+        total.sum
+    }
+
+    fn finish2(total: Unfolded) -> u64 {
+        total.sum
+    }
+}
+
+/// Arrangements of the rows so far, each unfolded `unfold` times.
+#[derive(Default)]
+pub struct Unfolded {
+    unfold: usize,
+    sum: u64,
+}
+
+impl Unfolded {
+    fn new(params: &Params) -> Self {
+        Self {
+            unfold: params.get("unfold"),
+            sum: 0,
+        }
+    }
+
+    fn add(&mut self, row: &Row) {
+        self.sum += arrangements(&row.unfold(self.unfold));
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct Row {
+    records: Vec<Status>,
+    rules: Vec<usize>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+enum Status {
+    Unknown,
+    Op,
+    Damage,
+}
+
+impl Row {
+    /// repeats the records (joined by '?') and the rules `duplicates` times
+    fn unfold(&self, duplicates: usize) -> Self {
+        let mut records = self.records.clone();
+        for _ in 1..duplicates {
+            records.push(Status::Unknown);
+            records.extend(self.records.iter().cloned());
+        }
+
+        Row {
+            records,
+            rules: self.rules.repeat(duplicates),
+        }
+    }
+}
+
+fn parse(input: &str) -> Result<Vec<Row>, ParseError> {
+    input.lines().map(parse_row).collect()
+}
+
+fn parse_row(line: &str) -> Result<Row, ParseError> {
+    let (left, right) = parse::split_once(line, " ")?;
+
+    let records = left
+        .char_indices()
+        .map(|(i, c)| match c {
+            '?' => Ok(Status::Unknown),
+            '.' => Ok(Status::Op),
+            '#' => Ok(Status::Damage),
+            _ => Err(ParseError::new(
+                &left[i..i + c.len_utf8()],
+                "`?`, `.` or `#`",
+            )),
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let rules = right
+        .split(',')
+        .map(parse::number)
+        .collect::<Result<Vec<usize>, _>>()?;
+
+    Ok(Row { records, rules })
+}
+
+fn total(rows: &[Row], unfold: usize) -> u64 {
+    par::map(rows.iter(), |row| arrangements(&row.unfold(unfold)))
+        .into_iter()
+        .sum()
+}
+
+fn arrangements(row: &Row) -> u64 {
+    let mut memo: HashMap<(usize, usize, usize), u64> = HashMap::new();
+    dp(&row.records, None, &row.rules, &mut memo)
+}
+
+fn dp(
+    records: &[Status],
+    consect_count: Option<usize>,
+    rules: &[usize],
+    memo: &mut HashMap<(usize, usize, usize), u64>,
+) -> u64 {
+    use Status as S;
+
+    if records.is_empty() {
+        if let Some(x) = consect_count {
+            if rules.len() == 1 && x == rules[0] {
+                return 1;
+            }
+        } else if rules.is_empty() {
+            return 1;
+        }
+        return 0;
+    }
+
+    if rules.is_empty() {
+        return match consect_count {
+            None => {
+                if records.is_empty() || !records.contains(&S::Damage) {
+                    1
+                } else {
+                    0
+                }
+            }
+            _ => unreachable!(),
+        };
+    }
+
+    let key = (records.len(), consect_count.unwrap_or(0), rules.len());
+    if let Some(&x) = memo.get(&key) {
+        stats::count("memo_hits");
+        return x;
+    }
+    stats::count("memo_misses");
+
+    let ret = match (&records[0], consect_count) {
+        (S::Damage, Some(x)) => dp(&records[1..], Some(x + 1), rules, memo),
+        (S::Damage, None) => dp(&records[1..], Some(1), rules, memo),
+        (S::Op, Some(x)) => {
+            if x != rules[0] {
+                0
+            } else {
+                dp(&records[1..], None, &rules[1..], memo)
+            }
+        }
+        (S::Op, None) => dp(&records[1..], None, rules, memo),
+        (S::Unknown, Some(x)) => {
+            dp(&records[1..], Some(x + 1), rules, memo)
+                + if x != rules[0] {
+                    0
+                } else {
+                    dp(&records[1..], None, &rules[1..], memo)
+                }
+        }
+        (S::Unknown, None) => {
+            dp(&records[1..], Some(1), rules, memo) + dp(&records[1..], None, rules, memo)
+        }
+    };
+
+    memo.insert(key, ret);
+
+    ret
+}
+
+#[test]
+fn test_rows() {
+    assert_eq!(total(&parse("????? 1,1").unwrap(), 1), 6);
+    assert_eq!(total(&parse("?????.?##?? 1,1,4").unwrap(), 1), 12);
+    assert_eq!(total(&parse("??..#??#.??? 1,1").unwrap(), 1), 1);
+
+    assert_eq!(total(&parse("???.### 1,1,3").unwrap(), 5), 1);
+    assert_eq!(total(&parse(".??..??...?##. 1,1,3").unwrap(), 5), 16384);
+    assert_eq!(total(&parse("?###???????? 3,2,1").unwrap(), 5), 506250);
+}
+
+#[cfg(test)]
+impl crate::prop::Shrink for Row {
+    /// fewer springs or groups, smaller groups, or fewer unknowns
+    fn shrink(&self) -> Vec<Self> {
+        let mut out = Vec::new();
+        for i in 0..self.records.len() {
+            let mut records = self.records.clone();
+            records.remove(i);
+            out.push(Row {
+                records,
+                rules: self.rules.clone(),
+            });
+        }
+        for i in 0..self.rules.len() {
+            let mut smaller = vec![self.rules.clone(), self.rules.clone()];
+            smaller[0].remove(i);
+            smaller[1][i] -= 1;
+            out.extend(
+                smaller
+                    .into_iter()
+                    .filter(|rules| !rules.is_empty() && !rules.contains(&0))
+                    .map(|rules| Row {
+                        records: self.records.clone(),
+                        rules,
+                    }),
+            );
+        }
+        for (i, status) in self.records.iter().enumerate() {
+            if *status == Status::Unknown {
+                for known in [Status::Op, Status::Damage] {
+                    let mut row = self.clone();
+                    row.records[i] = known;
+                    out.push(row);
+                }
+            }
+        }
+        out
+    }
+}
+
+#[test]
+fn matches_enumeration() {
+    use crate::gen::Rng;
+
+    let random_row = |rng: &mut Rng| Row {
+        records: (0..rng.range(1..=12))
+            .map(|_| {
+                rng.pick(&[Status::Unknown, Status::Op, Status::Damage])
+                    .clone()
+            })
+            .collect(),
+        rules: (0..rng.range(1..=4)).map(|_| rng.range(1..=4)).collect(),
+    };
+
+    // every way of filling in the unknowns, kept if its groups match
+    let enumerate = |row: &Row| {
+        let unknowns = row
+            .records
+            .iter()
+            .filter(|&s| *s == Status::Unknown)
+            .count();
+        (0..1_u32 << unknowns)
+            .filter(|mask| {
+                let mut bit = 0;
+                let damaged = row
+                    .records
+                    .iter()
+                    .map(|s| match s {
+                        Status::Unknown => {
+                            bit += 1;
+                            mask >> (bit - 1) & 1 == 1
+                        }
+                        s => *s == Status::Damage,
+                    })
+                    .collect::<Vec<_>>();
+                let groups = damaged
+                    .split(|&d| !d)
+                    .filter(|run| !run.is_empty())
+                    .map(<[bool]>::len)
+                    .collect::<Vec<_>>();
+                groups == row.rules
+            })
+            .count() as u64
+    };
+
+    crate::prop::agree(500, random_row, arrangements, enumerate);
+    crate::prop::agree(
+        100,
+        |rng| {
+            let mut row = random_row(rng);
+            row.records.truncate(5);
+            row
+        },
+        |row| arrangements(&row.unfold(3)),
+        |row| enumerate(&row.unfold(3)),
+    );
+}
@@ -0,0 +1,123 @@
+use crate::grid::Grid;
+use crate::parse::{self, ParseError};
+use crate::Solution;
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    const DAY: u32 = 13;
+    type Input<'a> = Vec<Grid<char>>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Vec<Grid<char>>, ParseError> {
+        parse(input)
+    }
+
+    fn part1(patterns: &Vec<Grid<char>>) -> usize {
+        part1(patterns)
+    }
+
+    fn part2(patterns: &Vec<Grid<char>>) -> usize {
+        part2(patterns)
+    }
+}
+
+fn parse(input: &str) -> Result<Vec<Grid<char>>, ParseError> {
+    input
+        .split("\n\n")
+        .map(|raw| parse::char_grid(raw, ".#"))
+        .collect()
+}
+
+/// (row, col) of the line of reflection; exactly one is expected to be set
+type Reflection = (Option<usize>, Option<usize>);
+
+fn solve_with(patterns: &[Grid<char>], solver: &dyn Fn(&Grid<char>) -> Reflection) -> usize {
+    patterns
+        .iter()
+        .map(solver)
+        .fold(0, |acc, (row, col)| match (row, col) {
+            (Some(r), None) => acc + r * 100,
+            (None, Some(c)) => acc + c,
+            _ => unreachable!(),
+        })
+}
+
+fn part1(patterns: &[Grid<char>]) -> usize {
+    solve_with(patterns, &|grid| compute(grid, (None, None)))
+}
+
+fn part2(patterns: &[Grid<char>]) -> usize {
+    solve_with(patterns, &compute2)
+}
+
+fn compute(
+    grid: &Grid<char>,
+    original: (Option<usize>, Option<usize>),
+) -> (Option<usize>, Option<usize>) {
+    let row = (1..grid.rows()).find(|&i| {
+        let ok = (0..(i.min(grid.rows() - i))).all(|di| grid.row(i - di - 1) == grid.row(i + di));
+        if ok {
+            if let Some(r) = original.0 {
+                if r != i {
+                    return true;
+                }
+            } else {
+                return true;
+            }
+        }
+
+        false
+    });
+
+    let col = (1..grid.cols()).find(|&j| {
+        let ok =
+            (0..(j.min(grid.cols() - j))).all(|dj| grid.column(j - dj - 1).eq(grid.column(j + dj)));
+        if ok {
+            if let Some(c) = original.1 {
+                if c != j {
+                    return true;
+                }
+            } else {
+                return true;
+            }
+        }
+
+        false
+    });
+
+    (row, col)
+}
+
+fn compute2(grid: &Grid<char>) -> (Option<usize>, Option<usize>) {
+    let original = compute(grid, (None, None));
+    let mut new_grid = grid.clone();
+    for pos in grid.positions() {
+        new_grid[pos] = match grid[pos] {
+            '.' => '#',
+            '#' => '.',
+            _ => unreachable!(),
+        };
+
+        match compute(&new_grid, original) {
+            (Some(r), None) => return (Some(r), None),
+            (None, Some(c)) => return (None, Some(c)),
+            (None, None) => (),
+            (Some(_), Some(_)) => unreachable!(),
+        }
+
+        new_grid[pos] = grid[pos];
+    }
+
+    unreachable!()
+}
+
+#[test]
+fn test_cases() {
+    let case = crate::fixture::input::<Day13>("tall");
+    let case_grid = parse::char_grid(&case, ".#").unwrap();
+
+    assert_eq!(compute(&case_grid, (None, None)), (Some(3), None));
+    assert_eq!(compute2(&case_grid), (Some(10), None));
+}
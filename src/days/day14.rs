@@ -0,0 +1,154 @@
+use std::io;
+
+use crate::cycle;
+use crate::grid::Grid;
+use crate::params::{Param, Params};
+use crate::parse::{self, ParseError};
+use crate::render::{self, Image, Renderer, Rgb};
+use crate::visual::Visualizer;
+use crate::Solution;
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    const DAY: u32 = 14;
+    type Input<'a> = Grid<char>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+    const PARAMS: &'static [Param] =
+        &[Param::new("cycles", "how many spin cycles the platform turns").part2("1000000000")];
+
+    fn parse(input: &str) -> Result<Grid<char>, ParseError> {
+        parse(input)
+    }
+
+    fn part1(grid: &Grid<char>) -> usize {
+        part1(grid)
+    }
+
+    fn part2(grid: &Grid<char>) -> usize {
+        Self::part2_with(grid, &Params::defaults(Self::PARAMS, 2))
+    }
+
+    fn part2_with(grid: &Grid<char>, params: &Params) -> usize {
+        part2(grid, params.get("cycles"))
+    }
+
+    fn render(grid: &Grid<char>, out: &mut Renderer) -> io::Result<()> {
+        render(grid, out)
+    }
+
+    fn visualize(grid: &Grid<char>, out: &mut Visualizer) {
+        visualize(grid, out)
+    }
+}
+
+fn picture(grid: &Grid<char>) -> Image {
+    render::image(grid, |_, &ch| match ch {
+        'O' => Rgb::YELLOW,
+        '#' => Rgb::GREY,
+        _ => Rgb::DARK,
+    })
+}
+
+/// A frame after every tilt, up to where the spin cycles start repeating.
+fn render(grid: &Grid<char>, out: &mut Renderer) -> io::Result<()> {
+    let history = cycle::hashed(grid.clone(), spin);
+    let spins = history.cycle.prefix + history.cycle.len;
+
+    out.frame("tilts", &picture(grid))?;
+    let mut acc = grid.clone();
+    for _ in 0..spins {
+        for turns in 0..4 {
+            let tilted = north(&acc, &mut |_, _| ());
+            // turned back the way the dish started
+            let upright = (0..turns).fold(tilted.clone(), |g, _| g.rotate_counterclockwise());
+            out.frame("tilts", &picture(&upright))?;
+            acc = tilted.rotate_clockwise();
+        }
+    }
+    Ok(())
+}
+
+/// Part 1's tilt a column at a time, then whole tilts of the spin cycles
+/// until they repeat.
+fn visualize(grid: &Grid<char>, out: &mut Visualizer) {
+    north(grid, &mut |partial, col| {
+        out.show(
+            &format!("tilting north: column {}", col + 1),
+            &picture(partial),
+        );
+    });
+
+    let cycle = cycle::hashed(grid.clone(), spin).cycle;
+    let mut acc = grid.clone();
+    for n in 0..cycle.prefix + cycle.len {
+        for (turns, name) in ["north", "west", "south", "east"].into_iter().enumerate() {
+            if out.stopped() {
+                return;
+            }
+            let tilted = north(&acc, &mut |_, _| ());
+            let upright = (0..turns).fold(tilted.clone(), |g, _| g.rotate_counterclockwise());
+            let title = format!(
+                "spin {} of {} before repeating every {}: {name}",
+                n + 1,
+                cycle.prefix + cycle.len,
+                cycle.len
+            );
+            out.show(&title, &picture(&upright));
+            acc = tilted.rotate_clockwise();
+        }
+    }
+}
+
+fn parse(input: &str) -> Result<Grid<char>, ParseError> {
+    parse::char_grid(input, ".#O")
+}
+
+fn part1(grid: &Grid<char>) -> usize {
+    weigh_grid(&north(grid, &mut |_, _| ()))
+}
+
+fn part2(grid: &Grid<char>, cycles: usize) -> usize {
+    let history = cycle::hashed(grid.clone(), spin);
+
+    weigh_grid(history.state_at(cycles))
+}
+
+/// north, west, south, then east
+fn spin(grid: &Grid<char>) -> Grid<char> {
+    (0..4).fold(grid.clone(), |acc, _| {
+        north(&acc, &mut |_, _| ()).rotate_clockwise()
+    })
+}
+
+/// Rolls every rock north, a column at a time; `observe` sees the grid
+/// after each column has settled.
+fn north(grid: &Grid<char>, observe: &mut impl FnMut(&Grid<char>, usize)) -> Grid<char> {
+    let mut ret = grid.clone();
+
+    (0..grid.cols()).for_each(|j| {
+        let mut curr_row = 0;
+        (0..grid.rows()).for_each(|i| match grid[(i, j)] {
+            'O' => {
+                ret[(i, j)] = '.';
+                ret[(curr_row, j)] = 'O';
+                curr_row += 1;
+            }
+            '#' => {
+                curr_row = i + 1;
+            }
+            _ => (),
+        });
+        observe(&ret, j);
+    });
+
+    ret
+}
+
+fn weigh_grid(grid: &Grid<char>) -> usize {
+    grid.iter_rows()
+        .enumerate()
+        .map(|(i, row)| row.iter().filter(|ch| **ch == 'O').count() * (grid.rows() - i))
+        .sum::<usize>()
+}
@@ -1,18 +1,50 @@
 use std::collections::VecDeque;
 
-fn main() {
-    let input: &str = include_str!("../../inputs/day15.txt");
-    println!("Part1: {}", part1(input));
-    println!("Part2: {}", part2(input));
+use crate::parse::{self, ParseError};
+use crate::Solution;
+
+pub struct Day15;
+
+impl Solution for Day15 {
+    const DAY: u32 = 15;
+    type Input<'a> = Vec<&'a str>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Vec<&str>, ParseError> {
+        parse(input)
+    }
+
+    fn part1(steps: &Vec<&str>) -> usize {
+        part1(steps)
+    }
+
+    fn part2(steps: &Vec<&str>) -> usize {
+        part2(steps)
+    }
 }
 
-fn part1(input: &str) -> usize {
+/// steps stay raw for part 1's hash, but must read as `label=N` or `label-`
+fn parse(input: &str) -> Result<Vec<&str>, ParseError> {
     input
         .strip_suffix('\n')
         .unwrap_or(input)
         .split(',')
-        .map(hash)
-        .sum()
+        .map(|step| {
+            match step.split_once('=') {
+                Some((_, num)) => {
+                    parse::number::<usize>(num)?;
+                }
+                None if step.ends_with('-') => (),
+                None => return Err(ParseError::new(step, "`label=N` or `label-`")),
+            }
+            Ok(step)
+        })
+        .collect()
+}
+
+fn part1(steps: &[&str]) -> usize {
+    steps.iter().map(|step| hash(step)).sum()
 }
 
 enum Op<'a> {
@@ -20,11 +52,9 @@ enum Op<'a> {
     Remove(&'a str),
 }
 
-fn part2(input: &str) -> usize {
-    input
-        .strip_suffix('\n')
-        .unwrap_or(input)
-        .split(',')
+fn part2(steps: &[&str]) -> usize {
+    steps
+        .iter()
         .map(|word| {
             if word.contains('=') {
                 let (label, num) = word.split_once('=').unwrap();
@@ -72,22 +102,8 @@ fn hash(word: &str) -> usize {
     })
 }
 
-#[test]
-fn example() {
-    let example: &str = "rn=1,cm-,qp=3,cm=2,qp-,pc=4,ot=9,ab=5,pc-,pc=6,ot=7";
-    assert_eq!(part1(example), 1320);
-    assert_eq!(part2(example), 145);
-}
-
 #[test]
 fn test_hash() {
     assert_eq!(hash("HASH"), 52);
     assert_eq!(hash("rn"), 0);
 }
-
-#[test]
-fn answer() {
-    let input: &str = include_str!("../../inputs/day15.txt");
-    assert_eq!(part1(input), 512283);
-    assert_eq!(part2(input), 215827);
-}
@@ -0,0 +1,221 @@
+use std::collections::{HashSet, VecDeque};
+use std::io;
+
+use crate::geom::Dir4;
+use crate::grid::{Grid, Pos};
+use crate::par;
+use crate::parse::{self, ParseError};
+use crate::render::{self, Image, Renderer, Rgb};
+use crate::visual::Visualizer;
+use crate::Solution;
+
+pub struct Day16;
+
+impl Solution for Day16 {
+    const DAY: u32 = 16;
+    type Input<'a> = Grid<char>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Grid<char>, ParseError> {
+        to_grid(input)
+    }
+
+    fn part1(grid: &Grid<char>) -> usize {
+        part1(grid)
+    }
+
+    fn part2(grid: &Grid<char>) -> usize {
+        part2(grid)
+    }
+
+    fn render(grid: &Grid<char>, out: &mut Renderer) -> io::Result<()> {
+        render(grid, out)
+    }
+
+    fn visualize(grid: &Grid<char>, out: &mut Visualizer) {
+        visualize(grid, out)
+    }
+}
+
+fn picture(grid: &Grid<char>, seen: &Grid<bool>) -> Image {
+    render::image(grid, |pos, &ch| match ch {
+        '.' if seen[pos] => Rgb::YELLOW,
+        '.' => Rgb::DARK,
+        _ if seen[pos] => Rgb::WHITE,
+        _ => Rgb::GREY,
+    })
+}
+
+/// part 1's beam, then one frame per entry beam of part 2
+fn render(grid: &Grid<char>, out: &mut Renderer) -> io::Result<()> {
+    let part1 = one_beam_grid(grid, Beam::new((0, 0), Dir4::Right));
+    out.image("energized", &picture(grid, &part1))?;
+    for start in entries(grid) {
+        out.frame("beams", &picture(grid, &one_beam_grid(grid, start)))?;
+    }
+    Ok(())
+}
+
+/// Part 1's beam spreading one straight run at a time, then the tiles each
+/// entry beam of part 2 energizes.
+fn visualize(grid: &Grid<char>, out: &mut Visualizer) {
+    let mut runs = 0;
+    energized(grid, Beam::new((0, 0), Dir4::Right), &mut |seen, end| {
+        runs += 1;
+        let mut image = picture(grid, seen);
+        image[end] = Rgb::RED;
+        out.show(&format!("beam from the top left: run {runs}"), &image);
+    });
+
+    let mut best = 0;
+    for start in entries(grid) {
+        if out.stopped() {
+            return;
+        }
+        let seen = one_beam_grid(grid, start);
+        let count = seen.iter().filter(|(_, &b)| b).count();
+        best = best.max(count);
+        let title = format!(
+            "entering at {:?} heading {:?}: {count} energized, best {best}",
+            start.pos, start.dir
+        );
+        out.show(&title, &picture(grid, &seen));
+    }
+}
+
+fn to_grid(input: &str) -> Result<Grid<char>, ParseError> {
+    parse::char_grid(input, ".|-/\\")
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+struct Beam {
+    pos: Pos,
+    dir: Dir4,
+}
+
+impl Beam {
+    fn new(pos: Pos, dir: Dir4) -> Self {
+        Self { pos, dir }
+    }
+
+    fn step_checked<T>(&self, grid: &Grid<T>) -> Option<Self> {
+        grid.offset(self.pos, self.dir)
+            .map(|pos| Self::new(pos, self.dir))
+    }
+}
+
+fn one_beam(grid: &Grid<char>, start: Beam) -> usize {
+    one_beam_grid(grid, start)
+        .iter()
+        .filter(|(_, &b)| b)
+        .count()
+}
+
+fn one_beam_grid(grid: &Grid<char>, start: Beam) -> Grid<bool> {
+    energized(grid, start, &mut |_, _| ())
+}
+
+/// Traces beams one straight run at a time; `observe` sees the energized
+/// tiles after each run, and where it ended.
+fn energized(
+    grid: &Grid<char>,
+    start: Beam,
+    observe: &mut impl FnMut(&Grid<bool>, Pos),
+) -> Grid<bool> {
+    let mut seen = Grid::new(grid.rows(), grid.cols(), false);
+
+    let mut history = HashSet::new();
+    let mut queue = VecDeque::new();
+    queue.push_back(start);
+    history.insert(start);
+
+    while !queue.is_empty() {
+        let mut beam = queue.pop_front().unwrap();
+
+        loop {
+            seen[beam.pos] = true;
+
+            match grid[beam.pos] {
+                '.' => (),
+                '/' => {
+                    beam.dir = beam.dir.reflect_forward();
+                }
+                '\\' => {
+                    beam.dir = beam.dir.reflect_backward();
+                }
+                '|' => {
+                    if !beam.dir.is_vertical() {
+                        let up = Beam::new(beam.pos, Dir4::Up);
+                        if !history.contains(&up) {
+                            queue.push_back(up);
+                            history.insert(up);
+                        }
+
+                        let down = Beam::new(beam.pos, Dir4::Down);
+                        if !history.contains(&down) {
+                            queue.push_back(down);
+                            history.insert(down);
+                        }
+
+                        break;
+                    }
+                }
+                '-' => {
+                    if beam.dir.is_vertical() {
+                        let left = Beam::new(beam.pos, Dir4::Left);
+                        if !history.contains(&left) {
+                            queue.push_back(left);
+                            history.insert(left);
+                        }
+
+                        let right = Beam::new(beam.pos, Dir4::Right);
+                        if !history.contains(&right) {
+                            queue.push_back(right);
+                            history.insert(right);
+                        }
+
+                        break;
+                    }
+                }
+                _ => unreachable!(),
+            }
+
+            if let Some(new_beam) = beam.step_checked(grid) {
+                beam = new_beam;
+            } else {
+                break;
+            }
+        }
+
+        observe(&seen, beam.pos);
+    }
+
+    seen
+}
+
+fn part1(grid: &Grid<char>) -> usize {
+    one_beam(grid, Beam::new((0, 0), Dir4::Right))
+}
+
+fn part2(grid: &Grid<char>) -> usize {
+    par::map(entries(grid), |start| one_beam(grid, start))
+        .into_iter()
+        .max()
+        .unwrap()
+}
+
+/// every beam entering from the edge
+fn entries(grid: &Grid<char>) -> impl Iterator<Item = Beam> {
+    let (rows, cols) = (grid.rows(), grid.cols());
+
+    let top = (0..cols).map(|c| Beam::new((0, c), Dir4::Down));
+
+    let bottom = (0..cols).map(move |c| Beam::new((rows - 1, c), Dir4::Up));
+
+    let left = (0..rows).map(|r| Beam::new((r, 0), Dir4::Right));
+
+    let right = (0..rows).map(move |r| Beam::new((r, cols - 1), Dir4::Left));
+
+    top.chain(bottom).chain(left).chain(right)
+}
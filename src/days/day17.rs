@@ -0,0 +1,153 @@
+use std::io;
+
+use crate::geom::Dir4;
+use crate::graph;
+use crate::grid::{Grid, Pos};
+use crate::params::{Param, Params};
+use crate::parse::{self, ParseError};
+use crate::render::{self, Renderer, Rgb};
+use crate::Solution;
+
+pub struct Day17;
+
+impl Solution for Day17 {
+    const DAY: u32 = 17;
+    type Input<'a> = Grid<usize>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+    const PARAMS: &'static [Param] = &[Param::new(
+        "crucible",
+        "least and most blocks the crucible moves in a straight line, `lo..hi`",
+    )
+    .part1("1..3")
+    .part2("4..10")];
+
+    fn parse(input: &str) -> Result<Grid<usize>, ParseError> {
+        parse(input)
+    }
+
+    fn part1(grid: &Grid<usize>) -> usize {
+        Self::part1_with(grid, &Params::defaults(Self::PARAMS, 1))
+    }
+
+    fn part2(grid: &Grid<usize>) -> usize {
+        Self::part2_with(grid, &Params::defaults(Self::PARAMS, 2))
+    }
+
+    fn part1_with(grid: &Grid<usize>, params: &Params) -> usize {
+        least_heat(grid, params.get("crucible"))
+    }
+
+    fn part2_with(grid: &Grid<usize>, params: &Params) -> usize {
+        least_heat(grid, params.get("crucible"))
+    }
+
+    fn render(grid: &Grid<usize>, out: &mut Renderer) -> io::Result<()> {
+        render(grid, out)
+    }
+}
+
+/// heat loss from dark (1) to light (9), with each part's route in red
+fn render(grid: &Grid<usize>, out: &mut Renderer) -> io::Result<()> {
+    for part in [1, 2] {
+        let (minstep, maxstep) = Params::defaults(Day17::PARAMS, part).get("crucible");
+        let name = format!("route{part}");
+        let mut image = render::image(grid, |_, &heat| {
+            Rgb::DARK.blend(Rgb::WHITE, (heat as f64 - 1.0) / 8.0)
+        });
+        render::paint(&mut image, dijkstra(grid, minstep, maxstep).1, Rgb::RED);
+        out.image(&name, &image)?;
+    }
+    Ok(())
+}
+
+fn parse(input: &str) -> Result<Grid<usize>, ParseError> {
+    parse::grid(input, "a digit", |c| c.to_digit(10).map(|d| d as usize))
+}
+
+fn least_heat(grid: &Grid<usize>, (minstep, maxstep): (isize, isize)) -> usize {
+    dijkstra(grid, minstep, maxstep).0 as usize
+}
+
+/// least heat loss, and every block along the way
+fn dijkstra(grid: &Grid<usize>, minstep: isize, maxstep: isize) -> (isize, Vec<Pos>) {
+    let end = (grid.rows() - 1, grid.cols() - 1);
+
+    // the direction is `None` before the first move
+    let start: (Pos, Option<Dir4>) = ((0, 0), None);
+    let successors = |&(coord, dir): &(Pos, Option<Dir4>)| {
+        let mut next = Vec::new();
+        for next_dir in Dir4::ALL {
+            if dir.is_some_and(|d| d == next_dir || d.opposite() == next_dir) {
+                continue;
+            }
+
+            let mut next_val = 0;
+            for dist in 1..=maxstep {
+                let Some(coord) = grid.offset(coord, next_dir.delta() * dist) else {
+                    continue;
+                };
+                next_val += grid[coord] as isize;
+                if dist >= minstep {
+                    next.push(((coord, Some(next_dir)), next_val));
+                }
+            }
+        }
+        next
+    };
+
+    let (heat, path) = graph::dijkstra(start, successors, |&(coord, _)| coord == end).unwrap();
+
+    // fill in the blocks crossed by each straight run
+    let mut blocks = vec![start.0];
+    for &(coord, dir) in &path[1..] {
+        let dir = dir.unwrap();
+        while *blocks.last().unwrap() != coord {
+            blocks.push(grid.offset(*blocks.last().unwrap(), dir).unwrap());
+        }
+    }
+
+    (heat, blocks)
+}
+
+#[test]
+#[should_panic]
+fn part2_test_cases_negative() {
+    let example: &str = r"111111111111";
+    least_heat(&parse(example).unwrap(), (4, 10));
+
+    let example: &str = r"1111111111111";
+    least_heat(&parse(example).unwrap(), (4, 10));
+
+    let example: &str = r"1111111111111111";
+    least_heat(&parse(example).unwrap(), (4, 10));
+
+    let example: &str = r"1
+1
+1
+1
+1
+1
+1
+1
+1
+1
+1
+1";
+    least_heat(&parse(example).unwrap(), (4, 10));
+
+    let example: &str = r"1
+1
+1
+1
+1
+1
+1
+1
+1
+1
+1
+1
+1";
+    least_heat(&parse(example).unwrap(), (4, 10));
+}
@@ -0,0 +1,105 @@
+use crate::geom::{Dir4, Point};
+use crate::parse::{self, ParseError};
+use crate::Solution;
+
+pub struct Day18;
+
+impl Solution for Day18 {
+    const DAY: u32 = 18;
+    type Input<'a> = Vec<(Instruction, Instruction)>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Vec<(Instruction, Instruction)>, ParseError> {
+        parse(input)
+    }
+
+    fn part1(plan: &Vec<(Instruction, Instruction)>) -> usize {
+        part1(plan)
+    }
+
+    fn part2(plan: &Vec<(Instruction, Instruction)>) -> usize {
+        part2(plan)
+    }
+}
+
+#[derive(Clone, Copy)]
+pub struct Instruction {
+    dir: Dir4,
+    dist: isize,
+}
+
+/// each line read both ways: as written, and decoded from the hex colour
+fn parse(input: &str) -> Result<Vec<(Instruction, Instruction)>, ParseError> {
+    input
+        .lines()
+        .map(|line| {
+            let mut splits = line.split_whitespace();
+
+            let dir_str = parse::next(&mut splits, line, "a direction")?;
+            let dir = match dir_str.chars().collect::<Vec<_>>()[..] {
+                [ch @ ('R' | 'D' | 'L' | 'U')] => Dir4::from_letter(ch).unwrap(),
+                _ => return Err(ParseError::new(dir_str, "`R`, `D`, `L` or `U`")),
+            };
+            let plain = Instruction {
+                dir,
+                dist: parse::number(parse::next(&mut splits, dir_str, "a distance")?)?,
+            };
+
+            let hex = parse::next(&mut splits, line, "a colour")?;
+            let digits = hex
+                .strip_prefix("(#")
+                .and_then(|s| s.strip_suffix(')'))
+                .filter(|s| s.len() == 6)
+                .ok_or_else(|| ParseError::new(hex, "a colour like `(#70c710)`"))?;
+
+            let dist = isize::from_str_radix(&digits[..5], 16)
+                .map_err(|_| ParseError::new(&digits[..5], "a hex distance"))?;
+            let dir = digits[5..]
+                .chars()
+                .next()
+                .and_then(Dir4::from_hex_digit)
+                .ok_or_else(|| ParseError::new(&digits[5..], "a direction digit `0`-`3`"))?;
+
+            Ok((plain, Instruction { dir, dist }))
+        })
+        .collect()
+}
+
+fn part1(plan: &[(Instruction, Instruction)]) -> usize {
+    get_area(plan.iter().map(|(plain, _)| *plain)) as usize
+}
+
+fn part2(plan: &[(Instruction, Instruction)]) -> usize {
+    get_area(plan.iter().map(|(_, hex)| *hex)) as usize
+}
+
+fn get_area(instructions: impl Iterator<Item = Instruction>) -> isize {
+    struct Container {
+        prev: Point,
+        boundary_area: isize,
+        shoelace_area: isize,
+    }
+
+    let Container {
+        boundary_area,
+        shoelace_area,
+        ..
+    } = instructions.fold(
+        Container {
+            prev: Point::default(),
+            boundary_area: 0,
+            shoelace_area: 0,
+        },
+        |mut acc, instr| {
+            acc.boundary_area += instr.dist;
+            let next = acc.prev + instr.dir.delta() * instr.dist;
+            acc.shoelace_area += acc.prev.r * next.c - next.r * acc.prev.c;
+            acc.prev = next;
+
+            acc
+        },
+    );
+
+    shoelace_area.abs() / 2 + boundary_area / 2 + 1
+}
@@ -0,0 +1,318 @@
+use std::collections::{HashMap, VecDeque};
+
+use crate::interval::{Box4, Cuboid, Interval};
+use crate::parse::{self, ParseError};
+use crate::Solution;
+
+pub struct Day19;
+
+impl Solution for Day19 {
+    const DAY: u32 = 19;
+    type Input<'a> = System;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<System, ParseError> {
+        parse(input)
+    }
+
+    fn part1(system: &System) -> usize {
+        part1(system)
+    }
+
+    fn part2(system: &System) -> usize {
+        part2(system)
+    }
+}
+
+#[derive(Debug, Clone)]
+pub enum Cond {
+    Lt(usize, usize),
+    Gt(usize, usize),
+    Nil,
+}
+
+impl Cond {
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        fn custom_convert(s: &str) -> Result<usize, ParseError> {
+            match s {
+                "x" => Ok(0),
+                "m" => Ok(1),
+                "a" => Ok(2),
+                "s" => Ok(3),
+                _ => Err(ParseError::new(s, "`x`, `m`, `a` or `s`")),
+            }
+        }
+
+        if let Some((k, v)) = input.split_once('<') {
+            Ok(Cond::Lt(custom_convert(k)?, parse::number(v)?))
+        } else if let Some((k, v)) = input.split_once('>') {
+            Ok(Cond::Gt(custom_convert(k)?, parse::number(v)?))
+        } else {
+            Err(ParseError::new(input, "a condition like `a<2006`"))
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub enum Flow {
+    Goto(String),
+    Accept,
+    Reject,
+}
+
+impl Flow {
+    fn parse(input: &str) -> Self {
+        match input {
+            "A" => Flow::Accept,
+            "R" => Flow::Reject,
+            _ => Flow::Goto(input.to_string()),
+        }
+    }
+}
+
+type WorkflowMap = HashMap<String, Vec<(Cond, Flow)>>;
+type Part = Vec<usize>;
+type System = (WorkflowMap, Vec<Part>);
+
+fn parse(input: &str) -> Result<System, ParseError> {
+    let (workflows_str, parts_str) = parse::split_once(input, "\n\n")?;
+    let workflows = workflows_str
+        .lines()
+        .map(|l| {
+            let (id, rules_str) = parse::split_once(l, "{")?;
+            let id = id.to_string();
+            let rules = rules_str
+                .strip_suffix('}')
+                .ok_or_else(|| ParseError::new(&l[l.len()..], "`}`"))?
+                .split(',')
+                .map(|r| match r.split_once(':') {
+                    Some((k, v)) => Ok((Cond::parse(k)?, Flow::parse(v))),
+                    None => Ok((Cond::Nil, Flow::parse(r))),
+                })
+                .collect::<Result<Vec<_>, ParseError>>()?;
+            Ok((id, rules))
+        })
+        .collect::<Result<HashMap<_, _>, ParseError>>()?;
+
+    let parts = parts_str
+        .lines()
+        .map(|l| {
+            let ratings = l
+                .strip_prefix('{')
+                .and_then(|s| s.strip_suffix('}'))
+                .ok_or_else(|| ParseError::new(l, "a part like `{x=787,m=2655,a=1222,s=2876}`"))?;
+            let mut splits = ratings.split(',');
+            ["x=", "m=", "a=", "s="]
+                .iter()
+                .map(|key| {
+                    let rating = parse::next(&mut splits, ratings, &format!("`{key}`"))?;
+                    match rating.strip_prefix(key) {
+                        Some(v) => parse::number(v),
+                        None => Err(ParseError::new(rating, format!("`{key}`"))),
+                    }
+                })
+                .collect::<Result<Vec<_>, _>>()
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    Ok((workflows, parts))
+}
+
+fn part1((workflows, parts): &System) -> usize {
+    parts
+        .iter()
+        .filter(|p| accepts(workflows, p))
+        .flatten()
+        .sum()
+}
+
+fn accepts(workflows: &WorkflowMap, p: &Part) -> bool {
+    let mut curr_rules = workflows.get("in").unwrap();
+    loop {
+        for (cond, flow) in curr_rules {
+            match cond {
+                Cond::Lt(i, v) if p[*i] < *v => match flow {
+                    Flow::Goto(k) => {
+                        curr_rules = workflows.get(k).unwrap();
+                        break;
+                    }
+                    Flow::Accept => return true,
+                    Flow::Reject => return false,
+                },
+                Cond::Gt(i, v) if p[*i] > *v => match flow {
+                    Flow::Goto(k) => {
+                        curr_rules = workflows.get(k).unwrap();
+                        break;
+                    }
+                    Flow::Accept => return true,
+                    Flow::Reject => return false,
+                },
+                Cond::Nil => match flow {
+                    Flow::Goto(k) => {
+                        curr_rules = workflows.get(k).unwrap();
+                        break;
+                    }
+                    Flow::Accept => return true,
+                    Flow::Reject => return false,
+                },
+                _ => continue,
+            }
+        }
+    }
+}
+
+fn part2((workflows, _parts): &System) -> usize {
+    accepted(workflows).iter().map(Box4::volume).sum()
+}
+
+/// disjoint boxes of ratings that end up accepted
+fn accepted(workflows: &WorkflowMap) -> Vec<Box4<usize>> {
+    let mut accepted = Vec::new();
+
+    let mut queue = VecDeque::new();
+    queue.push_back((
+        workflows.get("in").unwrap(),
+        Cuboid([Interval::new(1, 4001); 4]),
+    ));
+
+    while let Some((rules, rgs)) = queue.pop_front() {
+        let mut rest = Some(rgs);
+        for (cond, flow) in rules {
+            let Some(curr) = rest else {
+                break;
+            };
+            // the ratings this rule sends on, and those left for the next
+            let (matched, unmatched) = match cond {
+                Cond::Lt(i, v) => curr.split_at(*i, *v),
+                Cond::Gt(i, v) => {
+                    let (below, above) = curr.split_at(*i, *v + 1);
+                    (above, below)
+                }
+                Cond::Nil => (Some(curr), None),
+            };
+            rest = unmatched;
+            let Some(matched) = matched else {
+                continue;
+            };
+            match flow {
+                Flow::Goto(k) => queue.push_back((workflows.get(k).unwrap(), matched)),
+                Flow::Accept => accepted.push(matched),
+                Flow::Reject => (),
+            };
+        }
+    }
+
+    accepted
+}
+
+#[test]
+fn accepted_boxes_match_brute_force() {
+    let example = crate::fixture::input::<Day19>("example");
+    let (workflows, _) = parse(&example).unwrap();
+    let boxes = accepted(&workflows);
+
+    // ratings on either side of every threshold, plus the extremes
+    let mut values = vec![1, 4000];
+    for (cond, _) in workflows.values().flatten() {
+        if let Cond::Lt(_, v) | Cond::Gt(_, v) = cond {
+            values.extend([v - 1, *v, v + 1]);
+        }
+    }
+
+    let mut seed = 19_u64;
+    for _ in 0..5000 {
+        let part: Part = (0..4)
+            .map(|_| {
+                seed = seed.wrapping_mul(6364136223846793005).wrapping_add(1);
+                values[(seed >> 33) as usize % values.len()]
+            })
+            .collect();
+        let point = [part[0], part[1], part[2], part[3]];
+        let inside = boxes.iter().filter(|b| b.contains(point)).count();
+        assert_eq!(inside, usize::from(accepts(&workflows, &part)), "{part:?}");
+    }
+}
+
+#[cfg(test)]
+#[derive(Clone, Debug)]
+struct Workflows(WorkflowMap);
+
+#[cfg(test)]
+impl crate::prop::Shrink for Workflows {
+    /// fewer rules, rules without conditions, or flows straight to `A`/`R`
+    fn shrink(&self) -> Vec<Self> {
+        let mut out = Vec::new();
+        let mut names = self.0.keys().collect::<Vec<_>>();
+        names.sort();
+        for name in names {
+            let rules = &self.0[name];
+            let mut push = |rules: Vec<(Cond, Flow)>| {
+                let mut smaller = self.clone();
+                smaller.0.insert(name.clone(), rules);
+                out.push(smaller);
+            };
+            for i in 0..rules.len() - 1 {
+                let mut fewer = rules.clone();
+                fewer.remove(i);
+                push(fewer);
+            }
+            for (i, (cond, flow)) in rules.iter().enumerate() {
+                if !matches!(cond, Cond::Nil) {
+                    let mut simpler = rules.clone();
+                    simpler[i].0 = Cond::Nil;
+                    push(simpler);
+                }
+                if let Flow::Goto(_) = flow {
+                    for end in [Flow::Accept, Flow::Reject] {
+                        let mut simpler = rules.clone();
+                        simpler[i].1 = end;
+                        push(simpler);
+                    }
+                }
+            }
+        }
+        out
+    }
+}
+
+#[test]
+fn accepted_volume_matches_every_part() {
+    use itertools::Itertools;
+
+    let random_workflows = |rng: &mut crate::gen::Rng| {
+        let workflows = rng.range(1..=5);
+        let input = crate::gen::day19(rng, workflows, 0);
+        Workflows(parse(&input).unwrap().0)
+    };
+
+    // parts only behave differently across a threshold, so one part per
+    // cell between thresholds stands for every part in it
+    let every_part = |Workflows(workflows): &Workflows| {
+        let mut cuts = vec![vec![1, 4001]; 4];
+        for (cond, _) in workflows.values().flatten() {
+            match *cond {
+                Cond::Lt(i, v) => cuts[i].push(v.clamp(1, 4001)),
+                Cond::Gt(i, v) => cuts[i].push((v + 1).clamp(1, 4001)),
+                Cond::Nil => (),
+            }
+        }
+        cuts.iter_mut()
+            .map(|axis| {
+                axis.sort_unstable();
+                axis.dedup();
+                axis.windows(2).map(|w| (w[0], w[1] - w[0])).collect_vec()
+            })
+            .multi_cartesian_product()
+            .filter(|cell| accepts(workflows, &cell.iter().map(|&(v, _)| v).collect()))
+            .map(|cell| cell.iter().map(|&(_, len)| len).product::<usize>())
+            .sum::<usize>()
+    };
+
+    crate::prop::agree(
+        300,
+        random_workflows,
+        |Workflows(workflows)| part2(&(workflows.clone(), Vec::new())),
+        every_part,
+    );
+}
@@ -2,10 +2,35 @@ use std::cell::RefCell;
 use std::collections::hash_map::{Entry, HashMap};
 use std::collections::{HashSet, VecDeque};
 
-fn main() {
-    let input: &str = include_str!("../../inputs/day20.txt");
-    println!("Part1: {}", part1(input));
-    println!("Part2: {}", part2(input));
+use crate::math::{self, Progression};
+use crate::parse::{self, ParseError};
+use crate::stats;
+use crate::{Assumption, Solution};
+
+pub struct Day20;
+
+impl Solution for Day20 {
+    const DAY: u32 = 20;
+    /// modules carry state, so each part builds a fresh network from these
+    type Input<'a> = Vec<Spec<'a>>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Vec<Spec<'_>>, ParseError> {
+        parse(input)
+    }
+
+    fn part1(specs: &Vec<Spec>) -> usize {
+        part1(specs)
+    }
+
+    fn part2(specs: &Vec<Spec>) -> usize {
+        part2(specs)
+    }
+
+    fn assumptions(specs: &Vec<Spec>) -> Vec<Assumption> {
+        assumptions(specs)
+    }
 }
 
 #[derive(Debug, PartialEq, Eq, Copy, Clone)]
@@ -155,31 +180,79 @@ impl Module for Broadcaster {
     }
 }
 
-fn parse(input: &str) -> HashMap<String, Box<dyn Module>> {
+/// one line of the input: `%name`, `&name` or `broadcaster`, and where it sends
+pub struct Spec<'a> {
+    prefix: Option<char>,
+    name: &'a str,
+    dests: Vec<&'a str>,
+}
+
+fn parse(input: &str) -> Result<Vec<Spec<'_>>, ParseError> {
+    input
+        .lines()
+        .map(|line| {
+            let (src, dest) = parse::split_once(line, " -> ")?;
+            let (prefix, name) = match src.chars().next() {
+                Some(c @ ('%' | '&')) => (Some(c), &src[1..]),
+                _ if src == "broadcaster" => (None, src),
+                _ => return Err(ParseError::new(src, "`%name`, `&name` or `broadcaster`")),
+            };
+            Ok(Spec {
+                prefix,
+                name,
+                dests: dest.split(", ").collect(),
+            })
+        })
+        .collect()
+}
+
+fn assumptions(specs: &[Spec]) -> Vec<Assumption> {
+    let feeds_rx = specs
+        .iter()
+        .filter(|spec| spec.dests.contains(&"rx"))
+        .collect::<Vec<_>>();
+
+    let names = feeds_rx
+        .iter()
+        .map(|spec| {
+            format!(
+                "{}{}",
+                spec.prefix.map_or(String::new(), String::from),
+                spec.name
+            )
+        })
+        .collect::<Vec<_>>();
+    let single_conjunction = matches!(feeds_rx[..], [spec] if spec.prefix == Some('&'));
+
+    vec![
+        Assumption::new(
+            "there is a broadcaster",
+            specs.iter().any(|s| s.name == "broadcaster"),
+            format!("{} modules", specs.len()),
+        ),
+        Assumption::new(
+            "only one conjunction feeds into rx",
+            single_conjunction,
+            format!("fed by [{}]", names.join(", ")),
+        ),
+    ]
+}
+
+fn build(specs: &[Spec]) -> HashMap<String, Box<dyn Module>> {
     let mut modules = HashMap::new();
     let mut conjuctions = HashSet::new();
 
-    input.lines().for_each(|line| {
-        let (src, dest) = line.split_once(" -> ").unwrap();
-        let dest = dest.split(", ").map(|s| s.to_string()).collect();
-        let (k, v) = match src.chars().next().unwrap() {
-            '%' => (
-                src[1..].to_string(),
-                Box::new(FlipFlop::new(dest)) as Box<dyn Module>,
-            ),
-            '&' => {
-                conjuctions.insert(&src[1..]);
-                (
-                    src[1..].to_string(),
-                    Box::new(Conjunction::new(dest)) as Box<dyn Module>,
-                )
+    specs.iter().for_each(|spec| {
+        let dest = spec.dests.iter().map(|s| s.to_string()).collect();
+        let v = match spec.prefix {
+            Some('%') => Box::new(FlipFlop::new(dest)) as Box<dyn Module>,
+            Some(_) => {
+                conjuctions.insert(spec.name);
+                Box::new(Conjunction::new(dest)) as Box<dyn Module>
             }
-            _ => (
-                src.to_string(),
-                Box::new(Broadcaster::new(dest)) as Box<dyn Module>,
-            ),
+            None => Box::new(Broadcaster::new(dest)) as Box<dyn Module>,
         };
-        modules.insert(k, v);
+        modules.insert(spec.name.to_string(), v);
     });
 
     modules.iter().for_each(|(id, module)| {
@@ -198,9 +271,9 @@ fn parse(input: &str) -> HashMap<String, Box<dyn Module>> {
     modules
 }
 
-fn part1(input: &str) -> usize {
+fn part1(specs: &[Spec]) -> usize {
     #[allow(unused_mut)] // Module has interior mutability
-    let mut modules = parse(input);
+    let mut modules = build(specs);
 
     (0..1000)
         .fold([0, 0], |mut acc, _| {
@@ -208,14 +281,15 @@ fn part1(input: &str) -> usize {
             queue.push_back(("", "broadcaster", Pulse::Low));
 
             while let Some((src, dest, pulse)) = queue.pop_front() {
+                stats::count("pulses");
                 match &pulse {
                     Pulse::Low => acc[0] += 1,
                     Pulse::High => acc[1] += 1,
                 }
 
-                if let Some(module) = modules.get(&dest.to_string()) {
+                if let Some(module) = modules.get(dest) {
                     for (next_dest, next_pulse) in module.handle_pulse(src, pulse) {
-                        queue.push_back((&dest, next_dest, next_pulse));
+                        queue.push_back((dest, next_dest, next_pulse));
                     }
                 }
             }
@@ -226,9 +300,9 @@ fn part1(input: &str) -> usize {
         .product()
 }
 
-fn part2(input: &str) -> usize {
+fn part2(specs: &[Spec]) -> usize {
     #[allow(unused_mut)] // Module has interior mutability
-    let mut modules = parse(input);
+    let mut modules = build(specs);
 
     // assumes only one conjunction feeds into rx
     let (pre_rx, pre_rx_conj) = &modules
@@ -238,11 +312,13 @@ fn part2(input: &str) -> usize {
 
     // appeasing the borrow checker
     let binding = pre_rx_conj.get_inputs().unwrap();
-    let mut targets: HashMap<_, Option<usize>> = binding
+    let mut targets: HashMap<_, Vec<usize>> = binding
         .iter()
-        .map(|s| ((s.as_str(), (*pre_rx).as_str()), None))
+        .map(|s| ((s.as_str(), (*pre_rx).as_str()), Vec::new()))
         .collect::<HashMap<_, _>>();
 
+    // each input's first high pulse and the gap to its second, which are
+    // assumed to repeat from then on
     let mut found = Vec::new();
     let mut curr = 0;
 
@@ -252,31 +328,22 @@ fn part2(input: &str) -> usize {
         curr += 1;
 
         while let Some((src, dest, pulse)) = queue.pop_front() {
+            stats::count("pulses");
             if let Entry::Occupied(mut o) = targets.entry((src, dest)) {
-                if pulse == Pulse::High {
-                    match o.get() {
-                        Some(prev) => {
-                            let new = num::integer::lcm(*prev, curr);
-                            if new == curr {
-                                // cycle found
-                                found.push(*prev);
-                                o.remove();
-                            } else {
-                                o.insert(Some(new));
-                            }
-                        }
-                        None => {
-                            o.insert(Some(curr));
-                        }
+                if pulse == Pulse::High && o.get().last() != Some(&curr) {
+                    o.get_mut().push(curr);
+                    if let [first, second] = o.get()[..] {
+                        found.push(Progression::new(first as u64, (second - first) as u64));
+                        o.remove();
                     }
                 }
             }
 
             if targets.is_empty() {
-                return found.into_iter().fold(1, num::integer::lcm);
+                return math::combine(found).unwrap().offset as usize;
             }
 
-            if let Some(module) = modules.get(&dest.to_string()) {
+            if let Some(module) = modules.get(dest) {
                 for (next_dest, next_pulse) in module.handle_pulse(src, pulse) {
                     queue.push_back((dest, next_dest, next_pulse));
                 }
@@ -284,27 +351,3 @@ fn part2(input: &str) -> usize {
         }
     }
 }
-
-#[test]
-fn example() {
-    let example: &str = r"broadcaster -> a, b, c
-%a -> b
-%b -> c
-%c -> inv
-&inv -> a";
-    assert_eq!(part1(example), 32000000);
-
-    let example: &str = r"broadcaster -> a
-%a -> inv, con
-&inv -> b
-%b -> con
-&con -> output";
-    assert_eq!(part1(example), 11687500);
-}
-
-#[test]
-fn answer() {
-    let input: &str = include_str!("../../inputs/day20.txt");
-    assert_eq!(part1(input), 787056720);
-    assert_eq!(part2(input), 212986464842911);
-}
@@ -0,0 +1,404 @@
+use std::collections::{HashSet, VecDeque};
+
+use itertools::Itertools;
+
+use crate::grid::{Grid, Pos};
+use crate::params::{Param, Params};
+use crate::parse::{self, ParseError};
+use crate::render::{self, Rgb};
+use crate::stats;
+use crate::visual::Visualizer;
+use crate::{Assumption, Solution};
+
+pub struct Day21;
+
+type Garden = (Grid<char>, Pos);
+
+impl Solution for Day21 {
+    const DAY: u32 = 21;
+    type Input<'a> = Garden;
+    type Answer1 = usize;
+    type Answer2 = usize;
+    const PARAMS: &'static [Param] = &[Param::new("steps", "how far the elf walks")
+        .part1("64")
+        .part2("26501365")];
+
+    fn parse(input: &str) -> Result<Garden, ParseError> {
+        to_grid(input)
+    }
+
+    fn part1(garden: &Garden) -> usize {
+        Self::part1_with(garden, &Params::defaults(Self::PARAMS, 1))
+    }
+
+    fn part2(garden: &Garden) -> usize {
+        Self::part2_with(garden, &Params::defaults(Self::PARAMS, 2))
+    }
+
+    fn part1_with(garden: &Garden, params: &Params) -> usize {
+        part1(garden, params.get("steps"))
+    }
+
+    fn part2_with(garden: &Garden, params: &Params) -> usize {
+        part2(garden, params.get("steps"))
+    }
+
+    fn assumptions(garden: &Garden) -> Vec<Assumption> {
+        assumptions(garden, Params::defaults(Self::PARAMS, 2).get("steps"))
+    }
+
+    fn visualize(garden: &Garden, out: &mut Visualizer) {
+        visualize(garden, out)
+    }
+}
+
+/// The plots reachable after each step, over the garden and its eight
+/// neighbouring copies, until the walk reaches the edge of the copies
+/// (the second point `part2` interpolates through).
+fn visualize((grid, start): &Garden, out: &mut Visualizer) {
+    let size = grid.rows() as isize;
+    let tiled = Grid::new(grid.rows() * 3, grid.cols() * 3, ());
+    let steps = grid.rows() + grid.rows() / 2;
+
+    brute_force(grid, *start, &[steps], &mut |step, reached| {
+        let image = render::image(&tiled, |(r, c), _| {
+            let pos = (r as isize - size, c as isize - size);
+            if reached.contains(&pos) {
+                Rgb::GREEN
+            } else if *grid.get_wrapping(pos) == '#' {
+                Rgb::GREY
+            } else {
+                Rgb::DARK
+            }
+        });
+        out.show(
+            &format!("step {step}: {} plots reachable", reached.len()),
+            &image,
+        );
+    });
+}
+
+/// the "special format" the interpolation in `part2` needs
+fn assumptions((grid, start): &Garden, maxstep: usize) -> Vec<Assumption> {
+    let size = grid.rows();
+    let (r, c) = *start;
+    let clear_row = grid.row(r).iter().all(|&ch| ch != '#');
+    let clear_col = grid.column(c).all(|&ch| ch != '#');
+
+    vec![
+        Assumption::new(
+            "the garden is square",
+            grid.rows() == grid.cols(),
+            format!("{}x{}", grid.rows(), grid.cols()),
+        ),
+        Assumption::new(
+            "the start is in the centre",
+            size % 2 == 1 && *start == (size / 2, size / 2),
+            format!("at {start:?}"),
+        ),
+        Assumption::new(
+            "the start's row and column have no rocks",
+            clear_row && clear_col,
+            format!("row clear: {clear_row}, column clear: {clear_col}"),
+        ),
+        Assumption::new(
+            "the step count ends at the edge of a garden copy",
+            size > 0 && maxstep % size == size / 2,
+            format!(
+                "{maxstep} % {size} = {}",
+                maxstep.checked_rem(size).unwrap_or(0)
+            ),
+        ),
+    ]
+}
+
+fn to_grid(input: &str) -> Result<Garden, ParseError> {
+    let grid = parse::char_grid(input, ".#S")?;
+    let start = grid
+        .find(&'S')
+        .ok_or_else(|| ParseError::new(&input[input.len()..], "a start tile `S`"))?;
+
+    Ok((grid, start))
+}
+
+/// Number of plots reachable in exactly each of `targets` steps, over the
+/// infinitely repeated garden; `observe` sees the plots reachable after each
+/// step.
+fn brute_force(
+    grid: &Grid<char>,
+    (r_s, c_s): Pos,
+    targets: &[usize],
+    observe: &mut impl FnMut(usize, &HashSet<(isize, isize)>),
+) -> Vec<usize> {
+    let mut queue: VecDeque<((isize, isize), usize)> = VecDeque::new();
+    let mut visited: [HashSet<(isize, isize)>; 2] = [HashSet::new(), HashSet::new()];
+
+    let mut target_i = 0;
+    let mut ret = Vec::new();
+
+    queue.push_back(((r_s as isize, c_s as isize), 0));
+
+    let mut step = 0;
+    while let Some(((r, c), dist)) = queue.pop_front() {
+        if dist > step {
+            observe(step, &visited[step % 2]);
+            step = dist;
+            // this step's frontier, counting tiles queued more than once
+            stats::count("bfs_steps");
+            stats::peak("frontier_peak", queue.len() + 1);
+        }
+        if dist > targets[target_i] {
+            ret.push(visited[(targets[target_i]) % 2].len());
+
+            target_i += 1;
+            if target_i >= targets.len() {
+                break;
+            }
+        }
+
+        if visited.iter().any(|set| set.contains(&(r, c))) {
+            continue;
+        }
+        visited[dist % 2].insert((r, c));
+
+        for (dr, dc) in &[(0, 1), (0, -1), (1, 0), (-1, 0)] {
+            let (rr, cc) = ((r + dr), (c + dc));
+
+            if *grid.get_wrapping((rr, cc)) == '#' {
+                continue;
+            }
+            queue.push_back(((rr, cc), dist + 1));
+        }
+    }
+
+    ret
+}
+
+fn part1((grid, start): &Garden, maxstep: usize) -> usize {
+    brute_force(grid, *start, &[maxstep], &mut |_, _| ())[0]
+}
+
+/// the interpolation method only works for the given input with special format
+fn part2((grid, start): &Garden, maxstep: usize) -> usize {
+    // needs at least 3 points
+    // though using more points does not seem to change the result of regression
+    let x_data_len = 5;
+    let min_x = maxstep % grid.rows();
+    let max_x = min_x + grid.rows() * (x_data_len - 1);
+
+    let x_data = (min_x..=max_x).step_by(grid.rows()).collect_vec();
+    let y_data = brute_force(grid, *start, &x_data, &mut |_, _| ());
+
+    let ret_regression = regression_interpolate(maxstep, &x_data, &y_data);
+
+    let ret_lagrange = lagrange_interpolate(maxstep, &x_data, &y_data);
+
+    let ret_magic = magic_interpolate(maxstep / grid.rows(), &x_data, &y_data);
+
+    assert!(ret_regression == ret_lagrange && ret_lagrange == ret_magic);
+
+    ret_regression
+}
+
+#[cfg(feature = "linalg")]
+fn regression_interpolate(x: usize, x_data: &[usize], y_data: &[usize]) -> usize {
+    assert!(x_data.len() == y_data.len());
+
+    #[allow(unused_imports)]
+    use nalgebra::{DMatrix, Dyn, Matrix, MatrixXx1, VecStorage, U1};
+
+    type MatA = DMatrix<f64>;
+    type MatB = MatrixXx1<f64>;
+
+    // full types for above
+    // type MatA = Matrix<f64, Dyn, Dyn, VecStorage<f64, Dyn, Dyn>>;
+    // type MatB = Matrix<f64, Dyn, U1, VecStorage<f64, Dyn, U1>>;
+
+    let mat_a: MatA = MatA::from_row_slice(
+        x_data.len(),
+        3,
+        x_data
+            .iter()
+            .flat_map(|x| {
+                let x = *x as f64;
+                [x * x, x, 1_f64]
+            })
+            .collect::<Vec<_>>()
+            .as_slice(),
+    );
+
+    let mat_b: MatB =
+        MatB::from_column_slice(y_data.iter().map(|x| *x as f64).collect_vec().as_slice());
+
+    // seems like the library can only solve non-square matrices by svd
+    // I don't know enough about the various methods so just used whichever that worked
+    let res = mat_a.svd(true, true).solve(&mat_b, 1e-7).unwrap();
+
+    match res.data.as_slice() {
+        &[a, b, c] => {
+            let x = x as f64;
+            (a * x * x + b * x + c).round() as usize
+        }
+        _ => unreachable!(),
+    }
+}
+
+/// Without nalgebra: the least-squares quadratic from the normal equations
+/// `AᵀA c = Aᵀy`, solved exactly.
+#[cfg(not(feature = "linalg"))]
+fn regression_interpolate(x: usize, x_data: &[usize], y_data: &[usize]) -> usize {
+    use num::{BigRational, ToPrimitive};
+
+    assert!(x_data.len() == y_data.len());
+
+    let q = |x: usize| BigRational::from_integer(x.into());
+    let powers = |x: usize| [q(x * x), q(x), q(1)];
+
+    let rows = x_data.iter().map(|&x| powers(x)).collect_vec();
+    let ata = (0..3)
+        .map(|i| {
+            (0..3)
+                .map(|j| rows.iter().map(|r| &r[i] * &r[j]).sum())
+                .collect()
+        })
+        .collect();
+    let aty = (0..3)
+        .map(|i| rows.iter().zip(y_data).map(|(r, &y)| &r[i] * q(y)).sum())
+        .collect();
+
+    let coeffs = crate::math::solve_linear(ata, aty).unwrap();
+    let y = powers(x)
+        .iter()
+        .zip(&coeffs)
+        .map(|(p, c)| p * c)
+        .sum::<BigRational>();
+    y.round().to_integer().to_usize().unwrap()
+}
+
+fn lagrange_interpolate(x: usize, x_data: &[usize], y_data: &[usize]) -> usize {
+    let (x1, x2, x3) = match x_data[..3] {
+        [x1, x2, x3] => (x1 as isize, x2 as isize, x3 as isize),
+        _ => unreachable!(),
+    };
+    let (y1, y2, y3) = match y_data[..3] {
+        [y1, y2, y3] => (y1 as isize, y2 as isize, y3 as isize),
+        _ => unreachable!(),
+    };
+
+    let x = x as isize;
+
+    let term1 = y1 * ((x - x2) * (x - x3) / ((x1 - x2) * (x1 - x3)));
+    let term2 = y2 * ((x - x1) * (x - x3) / ((x2 - x1) * (x2 - x3)));
+    let term3 = y3 * ((x - x1) * (x - x2) / ((x3 - x1) * (x3 - x2)));
+
+    (term1 + term2 + term3) as usize
+}
+
+fn magic_interpolate(x: usize, _x_data: &[usize], y_data: &[usize]) -> usize {
+    // saw this on reddit; don't know what it is called
+    // x is maxstep / grid.rows(); different from the other two
+
+    let a = y_data[0] as isize;
+    let b = y_data[1] as isize;
+    let c = y_data[2] as isize;
+    let x = x as isize;
+    (a + x * (b - a) + x * (x - 1) / 2 * (c - 2 * b + a)) as usize
+}
+
+/// brute force the part2 examples, up to `steps`
+#[cfg(test)]
+fn brute_force_example(steps: &[usize]) -> Vec<usize> {
+    let example = crate::fixture::input::<Day21>("example");
+    let (grid, start) = to_grid(&example).unwrap();
+    brute_force(&grid, start, steps, &mut |_, _| ())
+}
+
+#[test]
+fn brute_force_small() {
+    assert_eq!(
+        brute_force_example(&[6, 10, 50, 100, 500]),
+        [16, 50, 1594, 6536, 167004]
+    );
+}
+
+#[test]
+#[ignore = "takes minutes in a debug build"]
+fn brute_force_large() {
+    assert_eq!(brute_force_example(&[1000, 5000]), [668697, 16733044]);
+}
+
+#[test]
+fn special_format() {
+    let garden = to_grid(&crate::fixture::input::<Day21>("example")).unwrap();
+    let holds = |maxstep| {
+        assumptions(&garden, maxstep)
+            .iter()
+            .map(|a| a.holds)
+            .collect::<Vec<_>>()
+    };
+    assert_eq!(holds(26501365), [true, true, false, false]);
+    assert_eq!(holds(5 + 11 * 100), [true, true, false, true]);
+}
+
+/// a garden in the special format, walked for `copies` whole garden widths
+/// past the first edge
+#[cfg(test)]
+#[derive(Clone, Debug)]
+struct Walk {
+    garden: Garden,
+    copies: usize,
+}
+
+#[cfg(test)]
+impl Walk {
+    fn maxstep(&self) -> usize {
+        let size = self.garden.0.rows();
+        size / 2 + self.copies * size
+    }
+}
+
+#[cfg(test)]
+impl crate::prop::Shrink for Walk {
+    /// fewer copies, or a rock turned into a plot
+    fn shrink(&self) -> Vec<Self> {
+        let mut out = Vec::new();
+        for copies in self.copies.shrink() {
+            out.push(Walk {
+                copies,
+                ..self.clone()
+            });
+        }
+        for (pos, _) in self.garden.0.iter().filter(|&(_, &ch)| ch == '#') {
+            let mut walk = self.clone();
+            walk.garden.0[pos] = '.';
+            out.push(walk);
+        }
+        out
+    }
+}
+
+#[test]
+fn interpolation_matches_brute_force() {
+    let random_walk = |rng: &mut crate::gen::Rng| {
+        let size = *rng.pick(&[5, 7, 9, 11]);
+        let start = (size / 2, size / 2);
+        let mut grid = Grid::new(size, size, '.');
+        for (r, c) in grid.positions().collect_vec() {
+            if r != start.0 && c != start.1 && rng.chance(0.2) {
+                grid[(r, c)] = '#';
+            }
+        }
+        grid[start] = 'S';
+        Walk {
+            garden: (grid, start),
+            copies: rng.range(0..=6),
+        }
+    };
+
+    crate::prop::agree(
+        100,
+        random_walk,
+        |walk| part2(&walk.garden, walk.maxstep()),
+        |walk| part1(&walk.garden, walk.maxstep()),
+    );
+}
@@ -0,0 +1,336 @@
+use std::cell::RefCell;
+use std::cmp::Reverse;
+use std::collections::{HashMap, HashSet};
+
+use itertools::Itertools;
+
+use crate::grid::Grid;
+use crate::par;
+use crate::parse::{self, ParseError};
+use crate::render::{Image, Rgb};
+use crate::stats;
+use crate::visual::Visualizer;
+use crate::Solution;
+
+pub struct Day22;
+
+type OverlapMemo = HashMap<(usize, usize), bool>;
+
+/// bricks after settling, plus the overlap memo shared by both parts
+type Settled = (Vec<Brick>, RefCell<OverlapMemo>);
+
+impl Solution for Day22 {
+    const DAY: u32 = 22;
+    type Input<'a> = Settled;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Settled, ParseError> {
+        let (bricks_stable, overlap_memo) = preprocess(input)?;
+        Ok((bricks_stable, RefCell::new(overlap_memo)))
+    }
+
+    fn part1((bricks_stable, overlap_memo): &Settled) -> usize {
+        part1(bricks_stable, &mut overlap_memo.borrow_mut())
+    }
+
+    // brute force: drops the stack again without each brick in turn, about
+    // 30 seconds on one thread
+    fn part2((bricks_stable, overlap_memo): &Settled) -> usize {
+        part2(bricks_stable, &mut overlap_memo.borrow_mut())
+    }
+
+    fn visualize((bricks_stable, overlap_memo): &Settled, out: &mut Visualizer) {
+        visualize(bricks_stable, &mut overlap_memo.borrow_mut(), out)
+    }
+}
+
+/// Part 2's chain reactions: for each brick whose removal lets others fall,
+/// the stack before every one-unit drop.
+fn visualize(bricks_stable: &[Brick], overlap_memo: &mut OverlapMemo, out: &mut Visualizer) {
+    for removed in 0..bricks_stable.len() {
+        if out.stopped() {
+            return;
+        }
+        let mut drops = 0;
+        let mut bricks = bricks_stable.to_vec();
+        freefall(
+            &mut bricks,
+            overlap_memo,
+            Some(removed),
+            &mut |bricks, moving| {
+                let falling = moving.iter().filter(|&&m| m).count();
+                if falling == 0 {
+                    return;
+                }
+                drops += 1;
+                let title = format!("without brick {removed}: drop {drops}, {falling} falling");
+                out.show(&title, &picture(bricks, moving, removed));
+            },
+        );
+    }
+}
+
+/// The stack from the side, along y above and along x below, with the
+/// ground on the left; falling bricks are red.
+fn picture(bricks: &[Brick], moving: &[bool], removed: usize) -> Image {
+    let max = |axis: fn(&Coord) -> usize| {
+        bricks
+            .iter()
+            .flat_map(|b| [axis(&b.lower), axis(&b.upper)])
+            .max()
+            .unwrap_or(0)
+    };
+    let (xs, ys, zs) = (max(|c| c.0) + 1, max(|c| c.1) + 1, max(|c| c.2));
+    let mut image = Grid::new(xs + 1 + ys, zs, Rgb::DARK);
+
+    let colour = |i: usize| {
+        if moving[i] {
+            Rgb::RED
+        } else {
+            Rgb::BLUE.blend(Rgb::GREEN, (i % 7) as f64 / 6.0)
+        }
+    };
+    let span = |a: usize, b: usize| a.min(b)..=a.max(b);
+
+    // for each view, far bricks first so nearer ones are drawn over them
+    let mut order = (0..bricks.len())
+        .filter(|&i| i != removed)
+        .collect::<Vec<_>>();
+    order.sort_by_key(|&i| Reverse(bricks[i].lower.1.min(bricks[i].upper.1)));
+    for &i in &order {
+        let Brick { lower, upper } = bricks[i];
+        for (z, x) in (lower.2..=upper.2).cartesian_product(span(lower.0, upper.0)) {
+            image[(x, z - 1)] = colour(i);
+        }
+    }
+    order.sort_by_key(|&i| Reverse(bricks[i].lower.0.min(bricks[i].upper.0)));
+    for &i in &order {
+        let Brick { lower, upper } = bricks[i];
+        for (z, y) in (lower.2..=upper.2).cartesian_product(span(lower.1, upper.1)) {
+            image[(xs + 1 + y, z - 1)] = colour(i);
+        }
+    }
+
+    image
+}
+
+type Coord = (usize, usize, usize);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Brick {
+    lower: Coord,
+    upper: Coord,
+}
+
+fn parse(input: &str) -> Result<Vec<Brick>, ParseError> {
+    input
+        .lines()
+        .map(|line| {
+            let (a, b) = parse::split_once(line, "~")?;
+            let coord = |s: &str| -> Result<Coord, ParseError> {
+                let mut splits = s.split(',');
+                let mut next = || parse::next(&mut splits, s, "`,`").and_then(parse::number);
+                Ok((next()?, next()?, next()?))
+            };
+            let coords = [coord(a)?, coord(b)?];
+
+            Ok(if coords[0].2 < coords[1].2 {
+                Brick {
+                    lower: coords[0],
+                    upper: coords[1],
+                }
+            } else {
+                Brick {
+                    lower: coords[1],
+                    upper: coords[0],
+                }
+            })
+        })
+        .collect()
+}
+
+fn preprocess(input: &str) -> Result<(Vec<Brick>, OverlapMemo), ParseError> {
+    let mut bricks_snapshot = parse(input)?;
+    bricks_snapshot.sort_by_key(|Brick { lower, upper }| (lower.2, upper.2));
+
+    let mut overlap_memo = HashMap::new();
+    let (bricks_stable, _) = freefall(
+        &mut bricks_snapshot,
+        &mut overlap_memo,
+        None,
+        &mut |_, _| (),
+    );
+
+    Ok((bricks_stable, overlap_memo))
+}
+
+fn is_xy_overlap(brick: &Brick, other: &Brick) -> bool {
+    let x_overlap = {
+        let ox = if other.lower.0 < other.upper.0 {
+            (other.lower.0, other.upper.0)
+        } else {
+            (other.upper.0, other.lower.0)
+        };
+        let bx = if brick.lower.0 < brick.upper.0 {
+            (brick.lower.0, brick.upper.0)
+        } else {
+            (brick.upper.0, brick.lower.0)
+        };
+
+        ox.0.max(bx.0) <= ox.1.min(bx.1)
+    };
+
+    let y_overlap = {
+        let oy = if other.lower.1 < other.upper.1 {
+            (other.lower.1, other.upper.1)
+        } else {
+            (other.upper.1, other.lower.1)
+        };
+        let by = if brick.lower.1 < brick.upper.1 {
+            (brick.lower.1, brick.upper.1)
+        } else {
+            (brick.upper.1, brick.lower.1)
+        };
+
+        oy.0.max(by.0) <= oy.1.min(by.1)
+    };
+
+    x_overlap && y_overlap
+}
+
+/// returns bricks in stable positions and the number of bricks that moved;
+/// `observe` sees the bricks before each one-unit drop, with the ones about
+/// to drop marked
+fn freefall(
+    bricks: &mut [Brick],
+    overlap_memo: &mut HashMap<(usize, usize), bool>,
+    skip_idx: Option<usize>,
+    observe: &mut impl FnMut(&[Brick], &[bool]),
+) -> (Vec<Brick>, usize) {
+    let mut moving = vec![true; bricks.len()];
+    let mut moved = HashSet::new();
+
+    if let Some(i) = skip_idx {
+        moving[i] = false;
+    }
+
+    let mut still_moving = moving.iter().any(|&b| b);
+    while still_moving {
+        stats::count("freefall_passes");
+        for i in 0..bricks.len() {
+            if !moving[i] {
+                continue;
+            }
+
+            let brick = &bricks[i];
+            if brick.lower.2 == 1 {
+                moving[i] = false;
+                continue;
+            }
+
+            for j in 0..i {
+                if moving[j] {
+                    continue;
+                }
+                match skip_idx {
+                    Some(idx) if idx == j => continue,
+                    _ => (),
+                }
+
+                let other = &bricks[j];
+                if brick.lower.2 - 1 == other.upper.2
+                    && *overlap_memo
+                        .entry((i, j))
+                        .or_insert(is_xy_overlap(brick, other))
+                {
+                    moving[i] = false;
+                    break;
+                }
+            }
+        }
+
+        observe(bricks, &moving);
+
+        for i in 0..bricks.len() {
+            if moving[i] {
+                let mut b = bricks[i];
+                b = Brick {
+                    lower: (b.lower.0, b.lower.1, b.lower.2 - 1),
+                    upper: (b.upper.0, b.upper.1, b.upper.2 - 1),
+                };
+                moved.insert(i);
+                if b.lower.2 == 1 {
+                    moving[i] = false;
+                }
+                bricks[i] = b;
+            }
+        }
+        still_moving = moving.iter().any(|&b| b);
+    }
+
+    (bricks.into(), moved.len())
+}
+
+fn check_bricks_support(
+    bricks_stable: &[Brick],
+    overlap_memo: &mut HashMap<(usize, usize), bool>,
+) -> (Vec<HashSet<usize>>, Vec<HashSet<usize>>) {
+    // idx is supporting val_set
+    let mut supporting = vec![HashSet::new(); bricks_stable.len()];
+
+    // idx is supported by val_set
+    let mut supported_by = vec![HashSet::new(); bricks_stable.len()];
+
+    for i in 0..bricks_stable.len() {
+        let brick = &bricks_stable[i];
+
+        if brick.lower.2 == 1 {
+            continue;
+        }
+
+        for j in 0..i {
+            let other = &bricks_stable[j];
+            if brick.lower.2 - 1 == other.upper.2
+                && *overlap_memo
+                    .entry((i, j))
+                    .or_insert(is_xy_overlap(brick, other))
+            {
+                supporting[j].insert(i);
+                supported_by[i].insert(j);
+            }
+        }
+    }
+
+    (supporting, supported_by)
+}
+
+fn part1(bricks_stable: &[Brick], overlap_memo: &mut HashMap<(usize, usize), bool>) -> usize {
+    let (supporting, supported_by) = check_bricks_support(bricks_stable, overlap_memo);
+
+    (0..bricks_stable.len())
+        .filter(|&i| {
+            if supporting[i].is_empty() {
+                true
+            } else {
+                supporting[i].iter().all(|&j| supported_by[j].len() > 1)
+            }
+        })
+        .count()
+}
+
+fn part2(bricks_stable: &[Brick], overlap_memo: &mut HashMap<(usize, usize), bool>) -> usize {
+    // each thread extends its own copy of the memo
+    let overlap_memo: &OverlapMemo = overlap_memo;
+
+    par::map_with(
+        0..bricks_stable.len(),
+        || overlap_memo.clone(),
+        |memo, i| {
+            let (_, moved) = freefall(&mut bricks_stable.to_vec(), memo, Some(i), &mut |_, _| ());
+            moved
+        },
+    )
+    .into_iter()
+    .sum()
+}
@@ -0,0 +1,191 @@
+use std::collections::{HashMap, VecDeque};
+use std::io;
+
+use crate::geom::Dir4;
+use crate::graph;
+use crate::grid::{Grid, Pos};
+use crate::parse::{self, ParseError};
+use crate::render::{self, Renderer, Rgb};
+use crate::{Assumption, Solution};
+
+pub struct Day23;
+
+impl Solution for Day23 {
+    const DAY: u32 = 23;
+    type Input<'a> = Grid<char>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Grid<char>, ParseError> {
+        to_grid(input)
+    }
+
+    fn part1(grid: &Grid<char>) -> usize {
+        part1(grid)
+    }
+
+    fn part2(grid: &Grid<char>) -> usize {
+        part2(grid)
+    }
+
+    fn assumptions(grid: &Grid<char>) -> Vec<Assumption> {
+        assumptions(grid)
+    }
+
+    fn render(grid: &Grid<char>, out: &mut Renderer) -> io::Result<()> {
+        let mut image = render::image(grid, |_, &ch| match ch {
+            '#' => Rgb::GREY,
+            _ => Rgb::DARK,
+        });
+        render::paint(&mut image, hike(grid).1, Rgb::RED);
+        out.image("hike", &image)
+    }
+}
+
+fn assumptions(grid: &Grid<char>) -> Vec<Assumption> {
+    let openings = |r: usize| {
+        (0..grid.cols())
+            .filter(|&c| grid[(r, c)] != '#')
+            .map(|c| (r, c))
+            .collect::<Vec<_>>()
+    };
+    let (top, bottom) = (openings(0), openings(grid.rows().saturating_sub(1)));
+    let end = (grid.rows().saturating_sub(1), grid.cols().saturating_sub(2));
+
+    let mut checks = vec![
+        Assumption::new(
+            "the only opening in the top row is (0, 1)",
+            top == [(0, 1)],
+            format!("openings {top:?}"),
+        ),
+        Assumption::new(
+            "the only opening in the bottom row is (rows - 1, cols - 2)",
+            bottom == [end],
+            format!("openings {bottom:?}"),
+        ),
+    ];
+
+    if top == [(0, 1)] {
+        let paths = |&pos: &Pos| {
+            grid.neighbors4(pos)
+                .filter(|&next| grid[next] != '#')
+                .collect::<Vec<_>>()
+        };
+        let junctions = graph::contract((0, 1), paths, |&pos| pos == end).len();
+        checks.push(Assumption::new(
+            "there are few enough junctions for the longest path search",
+            junctions <= 128,
+            format!("{junctions} junctions"),
+        ));
+    }
+
+    checks
+}
+
+fn to_grid(input: &str) -> Result<Grid<char>, ParseError> {
+    parse::char_grid(input, ".#^>v<")
+}
+
+fn part1(grid: &Grid<char>) -> usize {
+    // assume start and end
+    let start = (0, 1);
+    let end = (grid.rows() - 1, grid.cols() - 2);
+
+    let mut queue = VecDeque::new();
+    let mut memo = HashMap::new();
+
+    let mut ret = Vec::new();
+
+    queue.push_back((0, start, None::<Dir4>));
+
+    while let Some((cost, (r, c), dir)) = queue.pop_front() {
+        if (r, c) == end {
+            ret.push(cost);
+        }
+        if memo.get(&((r, c), dir)).is_some_and(|&v| cost < v) {
+            continue;
+        }
+
+        let dirs = match Dir4::from_arrow(grid[(r, c)]) {
+            Some(slope) => vec![slope],
+            None => Dir4::ALL.to_vec(),
+        };
+
+        for next_dir in dirs {
+            if dir.is_some_and(|d| d.opposite() == next_dir) {
+                continue;
+            }
+            let Some((rr, cc)) = grid.offset((r, c), next_dir) else {
+                continue;
+            };
+            if grid[(rr, cc)] == '#' {
+                continue;
+            }
+            if cost + 1 > *memo.get(&((rr, cc), Some(next_dir))).unwrap_or(&0) {
+                memo.insert(((rr, cc), Some(next_dir)), cost + 1);
+                queue.push_back((cost + 1, (rr, cc), Some(next_dir)));
+            }
+        }
+    }
+
+    ret.into_iter().max().unwrap()
+}
+
+fn part2(grid: &Grid<char>) -> usize {
+    hike(grid).0
+}
+
+/// The longest hike ignoring slopes, and every cell along it.
+fn hike(grid: &Grid<char>) -> (usize, Vec<Pos>) {
+    let start = (0, 1);
+    let end = (grid.rows() - 1, grid.cols() - 2);
+
+    // slopes are ordinary paths here, so the maze is undirected
+    let paths = |&pos: &Pos| {
+        grid.neighbors4(pos)
+            .filter(|&next| grid[next] != '#')
+            .collect::<Vec<_>>()
+    };
+    let junctions = graph::contract(start, paths, |&pos| pos == end);
+    let (len, route) = graph::longest_path(&junctions, &start, &end).unwrap();
+
+    // walk each corridor between consecutive junctions again to fill in the
+    // cells; of parallel corridors, the one of the right length
+    let mut cells = vec![start];
+    for (&from, &to) in route.iter().zip(&route[1..]) {
+        let steps = junctions[&from]
+            .iter()
+            .filter(|&&(next, _)| next == to)
+            .map(|&(_, steps)| steps)
+            .max()
+            .unwrap();
+        for first in paths(&from) {
+            let mut corridor = vec![first];
+            let mut prev = from;
+            while !junctions.contains_key(corridor.last().unwrap()) {
+                let curr = *corridor.last().unwrap();
+                match paths(&curr).into_iter().find(|&next| next != prev) {
+                    Some(next) => corridor.push(next),
+                    None => break,
+                }
+                prev = curr;
+            }
+            if corridor.last() == Some(&to) && corridor.len() == steps {
+                cells.extend(corridor);
+                break;
+            }
+        }
+    }
+
+    (len, cells)
+}
+
+#[test]
+fn hike_is_a_path() {
+    let example = crate::fixture::input::<Day23>("example");
+    let (len, cells) = hike(&to_grid(&example).unwrap());
+    assert_eq!(cells.len(), len + 1);
+    assert!(cells
+        .windows(2)
+        .all(|w| w[0].0.abs_diff(w[1].0) + w[0].1.abs_diff(w[1].1) == 1));
+}
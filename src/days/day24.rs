@@ -1,42 +1,65 @@
 use itertools::Itertools;
-use z3::ast::{Ast, Int};
 
-fn main() {
-    let input: &str = include_str!("../../inputs/day24.txt");
-    println!("Part1: {}", part1(input, (2e14, 4e14)));
-    println!("Part2: {}", part2(input));
+use crate::params::{Param, Params};
+use crate::parse::{self, ParseError};
+use crate::Solution;
+
+pub struct Day24;
+
+impl Solution for Day24 {
+    const DAY: u32 = 24;
+    type Input<'a> = Vec<Hailstone>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+    const PARAMS: &'static [Param] =
+        &[Param::new("area", "the test area's bounds on both axes, `lo..hi`").part1("2e14..4e14")];
+
+    fn parse(input: &str) -> Result<Vec<Hailstone>, ParseError> {
+        parse(input)
+    }
+
+    fn part1(hails: &Vec<Hailstone>) -> usize {
+        Self::part1_with(hails, &Params::defaults(Self::PARAMS, 1))
+    }
+
+    fn part1_with(hails: &Vec<Hailstone>, params: &Params) -> usize {
+        part1(hails, params.get("area"))
+    }
+
+    fn part2(hails: &Vec<Hailstone>) -> usize {
+        part2(hails)
+    }
 }
 
 type Coord = (f64, f64, f64);
 
 #[derive(Debug, Clone, Copy)]
-struct Hailstone {
+pub struct Hailstone {
     pos: Coord,
     vel: Coord,
 }
 
-fn parse(input: &str) -> Vec<Hailstone> {
+fn parse(input: &str) -> Result<Vec<Hailstone>, ParseError> {
     input
         .lines()
         .map(|line| {
-            let (pos, vel) = line
-                .split(" @ ")
-                .map(|part| {
-                    part.split(", ")
-                        .map(|num| num.trim().parse::<f64>().unwrap())
-                        .collect_tuple()
-                        .unwrap()
-                })
-                .collect_tuple()
-                .unwrap();
-            Hailstone { pos, vel }
+            let (pos, vel) = parse::split_once(line, " @ ")?;
+            let coord = |part: &str| -> Result<Coord, ParseError> {
+                let mut splits = part.split(',').map(str::trim);
+                let mut next = || parse::next(&mut splits, part, "`,`").and_then(parse::number);
+                Ok((next()?, next()?, next()?))
+            };
+            Ok(Hailstone {
+                pos: coord(pos)?,
+                vel: coord(vel)?,
+            })
         })
-        .collect_vec()
+        .collect()
 }
 
-fn part1(input: &str, (lbound, ubound): (f64, f64)) -> usize {
-    let segments = parse(input)
-        .into_iter()
+fn part1(hails: &[Hailstone], (lbound, ubound): (f64, f64)) -> usize {
+    let segments = hails
+        .iter()
         .map(|h| {
             // min and max t to be within box
             let mut t_lower = 0_f64;
@@ -98,11 +121,13 @@ fn part1(input: &str, (lbound, ubound): (f64, f64)) -> usize {
     count
 }
 
-fn part2(input: &str) -> usize {
+#[cfg(feature = "z3-solver")]
+fn part2(hails: &[Hailstone]) -> usize {
+    use z3::ast::{Ast, Int};
+
     // https://github.com/prove-rs/z3.rs/tree/master/z3
     // https://www.reddit.com/r/adventofcode/comments/18pnycy/comment/kepwd37/?utm_name=web3xcss
     // https://gist.github.com/WaterFace/1240609d0d4e15fa4ade3e471e7b501e
-    let hails = parse(input);
     let hails_to_use = hails.iter().take(3);
 
     let cfg = z3::Config::new();
@@ -145,20 +170,50 @@ fn part2(input: &str) -> usize {
     panic!("no solution");
 }
 
-#[test]
-fn example() {
-    let example: &str = r"19, 13, 30 @ -2,  1, -2
-18, 19, 22 @ -1, -1, -2
-20, 25, 34 @ -2, -2, -4
-12, 31, 28 @ -1, -2, -1
-20, 19, 15 @  1, -5, -3";
-    assert_eq!(part1(example, (7_f64, 27_f64)), 2);
-    assert_eq!(part2(example), 47);
-}
+/// Without z3: every hailstone meets the rock, so `(p - p_i) x (v - v_i) = 0`
+/// for each, and subtracting that for two hailstones leaves equations linear
+/// in the rock's `p` and `v`. Two pairs give six of them, solved exactly.
+#[cfg(not(feature = "z3-solver"))]
+fn part2(hails: &[Hailstone]) -> usize {
+    use num::{BigRational, ToPrimitive};
+
+    let ints = |(x, y, z): Coord| [x as i128, y as i128, z as i128];
+    let cross = |a: [i128; 3], b: [i128; 3]| {
+        [
+            a[1] * b[2] - a[2] * b[1],
+            a[2] * b[0] - a[0] * b[2],
+            a[0] * b[1] - a[1] * b[0],
+        ]
+    };
+
+    let solve = |hails: Vec<&Hailstone>| {
+        let (mut a, mut b) = (Vec::new(), Vec::new());
+        let (p0, v0) = (ints(hails[0].pos), ints(hails[0].vel));
+        for h in &hails[1..] {
+            let (p, v) = (ints(h.pos), ints(h.vel));
+            let dp = [0, 1, 2].map(|i| p0[i] - p[i]);
+            let dv = [0, 1, 2].map(|i| v0[i] - v[i]);
+            let (c0, c) = (cross(p0, v0), cross(p, v));
+
+            // p x dv + dp x v = p0 x v0 - p_i x v_i, in (px, py, pz, vx, vy, vz)
+            a.push([0, dv[2], -dv[1], 0, -dp[2], dp[1]]);
+            a.push([-dv[2], 0, dv[0], dp[2], 0, -dp[0]]);
+            a.push([dv[1], -dv[0], 0, -dp[1], dp[0], 0]);
+            b.extend([0, 1, 2].map(|i| c0[i] - c[i]));
+        }
 
-#[test]
-fn answer() {
-    let input: &str = include_str!("../../inputs/day24.txt");
-    assert_eq!(part1(input, (2e14, 4e14)), 25433);
-    assert_eq!(part2(input), 885093461440405);
+        let q = |x: i128| BigRational::from_integer(x.into());
+        let a = a.iter().map(|row| row.map(q).to_vec()).collect();
+        crate::math::solve_linear(a, b.into_iter().map(q).collect())
+    };
+
+    // any three hailstones pin the rock down unless some are parallel
+    let rock = hails
+        .iter()
+        .combinations(3)
+        .find_map(solve)
+        .expect("no solution");
+    let start = rock[..3].iter().sum::<BigRational>();
+    assert!(start.is_integer(), "rock starts off the integer grid");
+    start.to_integer().to_usize().unwrap()
 }
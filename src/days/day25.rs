@@ -0,0 +1,108 @@
+use std::collections::{HashMap, HashSet};
+use std::convert::Infallible;
+
+use itertools::Itertools;
+
+use crate::par;
+use crate::parse::{self, ParseError};
+use crate::Solution;
+
+pub struct Day25;
+
+type Wiring = (HashMap<String, usize>, Vec<(String, String)>);
+
+impl Solution for Day25 {
+    const DAY: u32 = 25;
+    const HAS_PART2: bool = false;
+    type Input<'a> = Wiring;
+    type Answer1 = usize;
+    type Answer2 = Infallible;
+
+    fn parse(input: &str) -> Result<Wiring, ParseError> {
+        parse(input)
+    }
+
+    fn part1(wiring: &Wiring) -> usize {
+        day25(wiring)
+    }
+
+    fn part2(_: &Wiring) -> Infallible {
+        unreachable!("day 25 has no second part")
+    }
+}
+
+fn parse(input: &str) -> Result<Wiring, ParseError> {
+    let mut nodes = HashSet::new();
+    let mut edges = Vec::new();
+
+    for line in input.lines() {
+        let (node, adjs_str) = parse::split_once(line, ": ")?;
+        nodes.insert(node.to_string());
+
+        for adj in adjs_str.split(' ').map(|s| s.to_string()) {
+            nodes.insert(adj.clone());
+            edges.push((node.to_string(), adj));
+        }
+    }
+
+    Ok((
+        nodes.into_iter().enumerate().map(|(i, x)| (x, i)).collect(),
+        edges,
+    ))
+}
+
+fn day25((nodes, edges): &Wiring) -> usize {
+    // tried in order of name, so threads find the same pair as a single one
+    // and every run finds the same pair
+    let size = par::find_map_first(nodes.iter().sorted().combinations(2), |pair_vec| {
+        let side = source_side(nodes, edges, *pair_vec[0].1, *pair_vec[1].1);
+        (side != 1 && side != nodes.len() - 1).then(|| side * (nodes.len() - side))
+    });
+
+    size.unwrap()
+}
+
+/// number of nodes on `source`'s side of a minimum cut from `sink`
+#[cfg(feature = "flow")]
+fn source_side(
+    nodes: &HashMap<String, usize>,
+    edges: &[(String, String)],
+    source: usize,
+    sink: usize,
+) -> usize {
+    // https://docs.rs/crate/network-flow/0.1.0
+    // https://docs.rs/network-flow/0.1.0/network_flow/graph/struct.Graph.html
+    use network_flow::graph::Graph;
+
+    let mut flow_graph = Graph::<usize, i64>::new();
+
+    for node in nodes.values() {
+        flow_graph.add_node(node);
+    }
+
+    let weight = edges.len() as i64;
+    for edge in edges.iter() {
+        flow_graph.add_edge(nodes[&edge.0], nodes[&edge.1], &weight);
+        flow_graph.add_edge(nodes[&edge.1], nodes[&edge.0], &weight);
+    }
+
+    // requires to run get_max_flow before get_cut
+    let _max_flow = flow_graph.get_max_flow(source, sink);
+    flow_graph.get_cut(source).len()
+}
+
+/// number of nodes on `source`'s side of a minimum cut from `sink`
+#[cfg(not(feature = "flow"))]
+fn source_side(
+    nodes: &HashMap<String, usize>,
+    edges: &[(String, String)],
+    source: usize,
+    sink: usize,
+) -> usize {
+    let edges = edges
+        .iter()
+        .map(|(a, b)| (nodes[a], nodes[b]))
+        .collect::<Vec<_>>();
+    let (_, side) = crate::graph::min_cut(nodes.len(), &edges, source, sink);
+    side.iter().filter(|&&s| s).count()
+}
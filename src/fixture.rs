@@ -0,0 +1,198 @@
+//! Puzzle examples kept as data files, `examples/<dd>/<name>.txt` (under
+//! `examples/<year>/` outside the default year). Each starts with a header
+//! of `key: value` lines ended by a `---` line: `part1` and `part2` give the
+//! expected answers, and any other key sets one of the day's
+//! [`Params`]. The rest of the file is the input:
+//!
+//! ```text
+//! expansion: 10
+//! part2: 1030
+//! ---
+//! ...#......
+//! ```
+//!
+//! Only the parts with an expected answer are run. The `examples` test runs
+//! every fixture of every year, and `aoc run --example NAME` runs one.
+
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use crate::params::Params;
+use crate::years;
+
+#[derive(Debug, Clone)]
+pub struct Fixture {
+    pub name: String,
+    /// `(part, answer)`, in the order given
+    pub expected: Vec<(u32, String)>,
+    pub params: Params,
+    pub input: String,
+}
+
+/// `examples/<dd>`, or `examples/<year>/<dd>` outside the default year.
+pub fn dir(year: u32, day: u32) -> PathBuf {
+    if year == years::DEFAULT {
+        PathBuf::from(format!("examples/{day:02}"))
+    } else {
+        PathBuf::from(format!("examples/{year}/{day:02}"))
+    }
+}
+
+impl Fixture {
+    pub fn parse(name: &str, text: &str) -> Result<Self, String> {
+        let mut fixture = Fixture {
+            name: name.to_string(),
+            expected: Vec::new(),
+            params: Params::default(),
+            input: String::new(),
+        };
+
+        let mut rest = text;
+        loop {
+            let (line, after) = rest.split_once('\n').unwrap_or((rest, ""));
+            if line.is_empty() && after.is_empty() {
+                return Err("no `---` line ending the header".to_string());
+            }
+            rest = after;
+
+            let line = line.trim_end_matches('\r');
+            if line == "---" {
+                break;
+            }
+            if line.trim().is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let (key, value) = line
+                .split_once(':')
+                .map(|(k, v)| (k.trim(), v.trim()))
+                .filter(|(k, v)| !k.is_empty() && !v.is_empty())
+                .ok_or_else(|| format!("expected `key: value`, found `{line}`"))?;
+            match key {
+                "part1" => fixture.expected.push((1, value.to_string())),
+                "part2" => fixture.expected.push((2, value.to_string())),
+                _ => fixture.params.set(key, value),
+            }
+        }
+
+        fixture.input = rest.to_string();
+        Ok(fixture)
+    }
+
+    /// The fixture `name` of `year`'s `day` in the crate at `root`.
+    pub fn load(root: &Path, year: u32, day: u32, name: &str) -> io::Result<Self> {
+        let path = root.join(dir(year, day)).join(format!("{name}.txt"));
+        let text = fs::read_to_string(&path)
+            .map_err(|e| io::Error::new(e.kind(), format!("{}: {e}", path.display())))?;
+        Self::parse(name, &text).map_err(|e| {
+            io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!("{}: {e}", path.display()),
+            )
+        })
+    }
+
+    /// The parts to run, those with an expected answer.
+    pub fn parts(&self) -> Vec<u32> {
+        self.expected.iter().map(|&(part, _)| part).collect()
+    }
+}
+
+/// Every fixture of `year`'s `day` in the crate at `root`, by name.
+pub fn all(root: &Path, year: u32, day: u32) -> io::Result<Vec<Fixture>> {
+    let entries = match fs::read_dir(root.join(dir(year, day))) {
+        Ok(entries) => entries,
+        Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(Vec::new()),
+        Err(e) => return Err(e),
+    };
+
+    let mut names = Vec::new();
+    for entry in entries {
+        let name = entry?.file_name();
+        if let Some(name) = name.to_str().and_then(|n| n.strip_suffix(".txt")) {
+            names.push(name.to_string());
+        }
+    }
+    names.sort();
+    names
+        .iter()
+        .map(|name| Fixture::load(root, year, day, name))
+        .collect()
+}
+
+/// The input of the fixture `name` of `S`, for tests that look inside a
+/// solution.
+#[cfg(test)]
+pub(crate) fn input<S: crate::Solution>(name: &str) -> String {
+    let root = Path::new(env!("CARGO_MANIFEST_DIR"));
+    Fixture::load(root, S::YEAR, S::DAY, name).unwrap().input
+}
+
+#[test]
+fn headers() {
+    let fixture = Fixture::parse(
+        "larger",
+        "# from the puzzle text\nexpansion: 10\r\npart2: 1030\n---\n#..\n---\n",
+    )
+    .unwrap();
+    assert_eq!(fixture.expected, [(2, "1030".to_string())]);
+    assert_eq!(fixture.params.to_string(), "expansion=10");
+    assert_eq!(fixture.parts(), [2]);
+    // the input may start with `#` and contain `---` itself
+    assert_eq!(fixture.input, "#..\n---\n");
+
+    assert!(Fixture::parse("x", "part1: 1\n#..\n").is_err());
+    assert!(Fixture::parse("x", "part1\n---\n").is_err());
+    assert!(Fixture::parse("x", "part1:\n---\n").is_err());
+}
+
+/// Runs every fixture of every year, whole and, for days that can, a line
+/// at a time.
+#[test]
+fn examples() {
+    let root = Path::new(env!("CARGO_MANIFEST_DIR"));
+    let mut failures = Vec::new();
+    let mut count = 0;
+
+    for &(year, days) in years::YEARS {
+        for solution in days {
+            let day = solution.day();
+            for fixture in all(root, year, day).unwrap() {
+                let at = format!("{year} day {day} {}", fixture.name);
+                let parts = fixture.parts();
+                if let Some(name) = fixture.params.unknown(solution.params()).next() {
+                    failures.push(format!("{at}: day takes no parameter {name}"));
+                    continue;
+                }
+                let mut runs = vec![solution.run_with(&fixture.input, &parts, &fixture.params)];
+                runs.extend(
+                    solution
+                        .stream(&mut fixture.input.as_bytes(), &parts, &fixture.params)
+                        .map(Result::unwrap),
+                );
+
+                for run in runs {
+                    if !matches!(run.parse.value, Some(Ok(()))) {
+                        failures.push(format!("{at}: parse failed"));
+                        continue;
+                    }
+                    for (part, expected) in &fixture.expected {
+                        let answer = run.answers.iter().find(|(p, _)| p == part);
+                        match answer.map(|(_, a)| &a.value) {
+                            Some(Some(answer)) if answer == expected => (),
+                            Some(Some(answer)) => failures.push(format!(
+                                "{at} part {part}: got {answer}, expected {expected}"
+                            )),
+                            Some(None) => failures.push(format!("{at} part {part}: panicked")),
+                            None => failures.push(format!("{at}: no part {part}")),
+                        }
+                    }
+                }
+                count += 1;
+            }
+        }
+    }
+
+    assert!(failures.is_empty(), "{}", failures.join("\n"));
+    assert!(count > 0);
+}
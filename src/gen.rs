@@ -0,0 +1,1239 @@
+//! Random puzzle inputs in each day's format, shaped like the real ones where
+//! a solution relies on it, for testing solutions on more than the examples
+//! and one private input. Everything is drawn from an [`Rng`] built from a seed, so a failing
+//! input can be made again from its seed (`aoc gen <day> --seed N`).
+
+use std::collections::{BTreeMap, BTreeSet, HashSet};
+use std::fmt::Write as _;
+use std::ops::RangeInclusive;
+
+use crate::geom::Dir4;
+use crate::grid::{Grid, Pos};
+
+/// splitmix64: small, fast, and good enough for test inputs
+#[derive(Debug, Clone)]
+pub struct Rng(u64);
+
+impl Rng {
+    pub fn new(seed: u64) -> Self {
+        Self(seed)
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+
+    /// uniform in `0..n`
+    ///
+    /// # Panics
+    ///
+    /// If `n` is zero.
+    pub fn below(&mut self, n: usize) -> usize {
+        assert!(n > 0, "empty range");
+        (self.next_u64() % n as u64) as usize
+    }
+
+    pub fn range(&mut self, range: RangeInclusive<usize>) -> usize {
+        range.start() + self.below(range.end() - range.start() + 1)
+    }
+
+    /// true with probability `p`
+    pub fn chance(&mut self, p: f64) -> bool {
+        ((self.next_u64() >> 11) as f64) < p * (1_u64 << 53) as f64
+    }
+
+    pub fn pick<'a, T>(&mut self, items: &'a [T]) -> &'a T {
+        &items[self.below(items.len())]
+    }
+
+    pub fn shuffle<T>(&mut self, items: &mut [T]) {
+        for i in (1..items.len()).rev() {
+            items.swap(i, self.below(i + 1));
+        }
+    }
+
+    fn lowercase(&mut self) -> char {
+        char::from(b'a' + self.below(26) as u8)
+    }
+
+    /// `n` distinct lowercase names, none of them in `taken`
+    fn names(&mut self, n: usize, taken: &[&str]) -> Vec<String> {
+        let mut seen: HashSet<String> = taken.iter().map(|s| s.to_string()).collect();
+        // the shortest length with room for twice as many, so few draws clash
+        let len = (2..)
+            .find(|&len| 26_usize.pow(len) >= 2 * (seen.len() + n))
+            .unwrap();
+        let mut names = Vec::new();
+        while names.len() < n {
+            let name = (0..len).map(|_| self.lowercase()).collect::<String>();
+            if seen.insert(name.clone()) {
+                names.push(name);
+            }
+        }
+        names
+    }
+}
+
+/// The generated input for `day` at roughly `size` (see each generator for
+/// what it scales), or `None` for a day that isn't a puzzle.
+pub fn input(day: u32, rng: &mut Rng, size: usize) -> Option<String> {
+    Some(match day {
+        1 => day01(rng, size),
+        2 => day02(rng, size),
+        3 => day03(rng, size),
+        4 => day04(rng, size),
+        5 => day05(rng, size, size),
+        6 => day06(rng, size),
+        7 => day07(rng, size),
+        8 => day08(rng, 6, size).0,
+        9 => day09(rng, size),
+        10 => day10(rng, size).0,
+        11 => day11(rng, size),
+        12 => day12(rng, size, 20),
+        13 => day13(rng, size),
+        14 => day14(rng, size),
+        15 => day15(rng, size),
+        16 => day16(rng, size),
+        17 => day17(rng, size),
+        18 => day18(rng, size),
+        19 => day19(rng, size, size * 2),
+        20 => day20(rng, size, 12).0,
+        21 => day21(rng, size),
+        22 => day22(rng, size),
+        23 => day23(rng, size),
+        24 => day24(rng, size).0,
+        25 => day25(rng, size).0,
+        _ => return None,
+    })
+}
+
+/// `lines` lines of letters, digits and spelled-out digits, each with at
+/// least one digit.
+pub fn day01(rng: &mut Rng, lines: usize) -> String {
+    const WORDS: [&str; 9] = [
+        "one", "two", "three", "four", "five", "six", "seven", "eight", "nine",
+    ];
+    let digit = |rng: &mut Rng| char::from(b'1' + rng.below(9) as u8);
+
+    let mut out = String::new();
+    for _ in 0..lines {
+        let len = rng.range(1..=40);
+        let mut line = String::new();
+        while line.len() < len {
+            match rng.below(10) {
+                0 | 1 => line.push(digit(rng)),
+                2..=4 => line += *rng.pick(&WORDS),
+                _ => line.push(rng.lowercase()),
+            }
+        }
+        if !line.contains(|c: char| c.is_ascii_digit()) {
+            let at = rng.range(0..=line.len());
+            line.insert(at, digit(rng));
+        }
+        let _ = writeln!(out, "{line}");
+    }
+    out
+}
+
+/// `games` games of 1 to 6 draws of up to 20 cubes of each colour.
+pub fn day02(rng: &mut Rng, games: usize) -> String {
+    let mut out = String::new();
+    for id in 1..=games {
+        let draws = (0..rng.range(1..=6))
+            .map(|_| {
+                let mut colours = ["red", "green", "blue"];
+                rng.shuffle(&mut colours);
+                let shown = rng.range(1..=3);
+                colours[..shown]
+                    .iter()
+                    .map(|colour| format!("{} {colour}", rng.range(1..=20)))
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            })
+            .collect::<Vec<_>>();
+        let _ = writeln!(out, "Game {id}: {}", draws.join("; "));
+    }
+    out
+}
+
+/// A `size` by `size` schematic of part numbers up to 999 and symbols, more
+/// of them `*` than any other.
+pub fn day03(rng: &mut Rng, size: usize) -> String {
+    const SYMBOLS: [char; 12] = ['*', '*', '*', '#', '+', '$', '/', '@', '=', '%', '&', '-'];
+    let size = size.max(1);
+
+    let mut grid = Grid::new(size, size, '.');
+    for r in 0..size {
+        let mut c = 0;
+        while c < size {
+            if rng.chance(0.15) {
+                // cut short at the edge, and with a gap before the next
+                let number = rng.range(1..=999).to_string();
+                for (i, digit) in number.chars().take(size - c).enumerate() {
+                    grid[(r, c + i)] = digit;
+                }
+                c += number.len() + 1;
+            } else {
+                if rng.chance(0.08) {
+                    grid[(r, c)] = *rng.pick(&SYMBOLS);
+                }
+                c += 1;
+            }
+        }
+    }
+    grid.to_string() + "\n"
+}
+
+/// `cards` scratchcards of 10 winning numbers and 25 numbers held. No card
+/// wins copies of cards past the last, and the copies won add up to at most
+/// about ten million, as in the puzzle.
+pub fn day04(rng: &mut Rng, cards: usize) -> String {
+    const BUDGET: u64 = 10_000_000;
+    let width = cards.to_string().len();
+
+    // copies of each card won so far; the ones before the current card are
+    // final, so their sum with the rest is the least the total can be
+    let mut held = vec![1_u64; cards];
+    let mut total = cards as u64;
+    let mut out = String::new();
+    for i in 0..cards {
+        let room = (BUDGET.saturating_sub(total) / held[i]) as usize;
+        let matches = rng.range(0..=10).min(cards - 1 - i).min(room);
+        let copies = held[i];
+        for won in &mut held[i + 1..=i + matches] {
+            *won += copies;
+        }
+        total += matches as u64 * copies;
+
+        let mut numbers = (1..=99).collect::<Vec<usize>>();
+        rng.shuffle(&mut numbers);
+        let (winning, rest) = numbers.split_at(10);
+        let mut mine = [&winning[..matches], &rest[..25 - matches]].concat();
+        rng.shuffle(&mut mine);
+
+        let show = |numbers: &[usize]| {
+            numbers
+                .iter()
+                .map(|n| format!("{n:>2}"))
+                .collect::<Vec<_>>()
+                .join(" ")
+        };
+        let _ = writeln!(
+            out,
+            "Card {:>width$}: {} | {}",
+            i + 1,
+            show(winning),
+            show(&mine)
+        );
+    }
+    out
+}
+
+/// `seeds` seed ranges and the seven maps, of up to `pieces` pieces each,
+/// over numbers below 2^32 as in the puzzle. Each map shuffles a run of
+/// consecutive pieces around, so no two overlap either way.
+pub fn day05(rng: &mut Rng, seeds: usize, pieces: usize) -> String {
+    const MAPS: [&str; 7] = [
+        "seed-to-soil",
+        "soil-to-fertilizer",
+        "fertilizer-to-water",
+        "water-to-light",
+        "light-to-temperature",
+        "temperature-to-humidity",
+        "humidity-to-location",
+    ];
+    const LIMIT: usize = 1 << 32;
+
+    let seeds = (0..seeds.max(1))
+        .map(|_| {
+            let len = rng.range(1..=1 << 28);
+            format!("{} {len}", rng.below(LIMIT - len))
+        })
+        .collect::<Vec<_>>();
+    let mut out = format!("seeds: {}\n", seeds.join(" "));
+
+    for name in MAPS {
+        let n = rng.range(1..=pieces.max(1));
+        let lens = (0..n).map(|_| rng.range(1..=LIMIT / n)).collect::<Vec<_>>();
+        let span = lens.iter().sum::<usize>();
+
+        let mut order = (0..n).collect::<Vec<_>>();
+        rng.shuffle(&mut order);
+        let mut dsts = vec![0; n];
+        let mut at = rng.below(LIMIT - span + 1);
+        for i in order {
+            dsts[i] = at;
+            at += lens[i];
+        }
+
+        let mut src = rng.below(LIMIT - span + 1);
+        let mut lines = Vec::new();
+        for (dst, len) in dsts.into_iter().zip(lens) {
+            lines.push(format!("{dst} {src} {len}"));
+            src += len;
+        }
+        rng.shuffle(&mut lines);
+        let _ = write!(out, "\n{name} map:\n{}\n", lines.join("\n"));
+    }
+    out
+}
+
+/// Up to four races, as in the puzzle, each with a record that can be
+/// beaten, also when they are read as one race by ignoring the spaces.
+pub fn day06(rng: &mut Rng, races: usize) -> String {
+    let races = races.clamp(1, 4);
+    // the farthest a boat goes in a race of `time`
+    let best = |time: u64| (time / 2) * (time - time / 2);
+    let joined = |numbers: &[u64]| {
+        numbers
+            .iter()
+            .map(u64::to_string)
+            .collect::<String>()
+            .parse::<u64>()
+            .unwrap()
+    };
+
+    loop {
+        let times = (0..races)
+            .map(|_| rng.range(7..=99) as u64)
+            .collect::<Vec<_>>();
+        let records = times
+            .iter()
+            .map(|&time| {
+                let best = best(time) as usize;
+                rng.range(best / 2..=best - 1) as u64
+            })
+            .collect::<Vec<_>>();
+        if joined(&records) < best(joined(&times)) {
+            let row = |numbers: &[u64]| {
+                numbers
+                    .iter()
+                    .map(|n| format!("{n:>5}"))
+                    .collect::<String>()
+            };
+            return format!("Time:    {}\nDistance:{}\n", row(&times), row(&records));
+        }
+    }
+}
+
+/// `hands` different hands (up to every one there is) with bids up to
+/// 1000. Each hand is drawn from a few ranks, so pairs and better are
+/// common.
+pub fn day07(rng: &mut Rng, hands: usize) -> String {
+    const CARDS: [char; 13] = [
+        '2', '3', '4', '5', '6', '7', '8', '9', 'T', 'J', 'Q', 'K', 'A',
+    ];
+    let hands = hands.min(CARDS.len().pow(5));
+
+    let mut seen = HashSet::new();
+    let mut out = String::new();
+    while seen.len() < hands {
+        let mut ranks = CARDS;
+        rng.shuffle(&mut ranks);
+        let ranks = &ranks[..rng.range(1..=5)];
+        let hand = (0..5).map(|_| *rng.pick(ranks)).collect::<String>();
+        if seen.insert(hand.clone()) {
+            let _ = writeln!(out, "{hand} {}", rng.range(1..=1000));
+        }
+    }
+    out
+}
+
+/// Directions of `turns` steps and a network for `ghosts` ghosts (1 to 6)
+/// built like the puzzle's. Each ghost walks a loop as long as the
+/// directions times a prime of its own, whose one `..Z` node comes at the
+/// end of each lap, and the ghost from `AAA` ends its laps at `ZZZ`. Also
+/// gives the first step on which every ghost is on a `..Z` node.
+pub fn day08(rng: &mut Rng, ghosts: usize, turns: usize) -> (String, u64) {
+    const PRIMES: [usize; 11] = [2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31];
+    let ghosts = ghosts.clamp(1, 6);
+    let turns = (0..turns.max(1))
+        .map(|_| *rng.pick(&['L', 'R']))
+        .collect::<String>()
+        .into_bytes();
+    let mut primes = PRIMES;
+    rng.shuffle(&mut primes);
+    let laps = primes[..ghosts]
+        .iter()
+        .map(|p| p * turns.len())
+        .collect::<Vec<_>>();
+
+    // the names on a lap end in neither `A` nor `Z`, apart from its `..Z`
+    let mut names = rng
+        .names(laps.iter().sum::<usize>() + ghosts, &["aa", "zz"])
+        .into_iter()
+        .map(|name| name.to_uppercase());
+    let mut lines = Vec::new();
+    let mut steps = 1;
+    for (g, &len) in laps.iter().enumerate() {
+        let (start, end) = match g {
+            0 => ("AAA".to_string(), "ZZZ".to_string()),
+            _ => {
+                let ghost = names.next().unwrap();
+                (format!("{ghost}A"), format!("{ghost}Z"))
+            }
+        };
+        let mut lap = vec![end];
+        for _ in 1..len {
+            let last = char::from(b'B' + rng.below(24) as u8);
+            lap.push(format!("{}{last}", names.next().unwrap()));
+        }
+
+        // step `j` of a lap is taken from `lap[j]`; the other way leads
+        // anywhere on the lap, and is never taken
+        let children = (0..len)
+            .map(|j| {
+                let (next, other) = (&lap[(j + 1) % len], rng.pick(&lap));
+                match turns[j % turns.len()] {
+                    b'L' => format!("({next}, {other})"),
+                    _ => format!("({other}, {next})"),
+                }
+            })
+            .collect::<Vec<_>>();
+        lines.push(format!("{start} = {}", children[0]));
+        for (node, children) in lap.iter().zip(&children) {
+            lines.push(format!("{node} = {children}"));
+        }
+        steps = num::integer::lcm(steps, len as u64);
+    }
+    rng.shuffle(&mut lines);
+
+    let turns = String::from_utf8(turns).unwrap();
+    (format!("{turns}\n\n{}\n", lines.join("\n")), steps)
+}
+
+/// `histories` histories of 21 values, each a polynomial of degree up to 6
+/// with small whole differences.
+pub fn day09(rng: &mut Rng, histories: usize) -> String {
+    let mut out = String::new();
+    for _ in 0..histories {
+        // the first of each row of differences, summed back up a step at a
+        // time
+        let mut firsts = (0..=rng.range(0..=6))
+            .map(|_| rng.range(0..=20) as i64 - 10)
+            .collect::<Vec<_>>();
+        let mut values = Vec::new();
+        for _ in 0..21 {
+            values.push(firsts[0].to_string());
+            for k in 1..firsts.len() {
+                firsts[k - 1] += firsts[k];
+            }
+        }
+        let _ = writeln!(out, "{}", values.join(" "));
+    }
+    out
+}
+
+/// A `size` by `size` pipe maze (at least 3) with a single loop through
+/// `S`, junk pipes inside and out, and nothing else joining `S`. Also gives
+/// where `S` is.
+///
+/// The loop goes round a random tree of 2x2 blocks, as in a maze drawn by
+/// following one wall, and is then doubled in size so it encloses tiles,
+/// some of them only reachable by squeezing between pipes.
+pub fn day10(rng: &mut Rng, size: usize) -> (String, Pos) {
+    let size = size.max(3);
+    let n = (size / 4).max(1);
+
+    // double the loop and place it anywhere it fits
+    let cycle = tree_cycle(rng, n);
+    let span = 4 * n - 1;
+    let (dr, dc) = (rng.below(size - span + 1), rng.below(size - span + 1));
+    let doubled = cycle
+        .iter()
+        .zip(cycle.iter().cycle().skip(1))
+        .flat_map(|(&(r, c), &(r2, c2))| [(2 * r, 2 * c), (r + r2, c + c2)])
+        .map(|(r, c)| (r + dr, c + dc))
+        .collect::<Vec<_>>();
+
+    let mut grid = Grid::new(size, size, '.');
+    for pos in grid.positions().collect::<Vec<_>>() {
+        if rng.chance(0.6) {
+            grid[pos] = *rng.pick(&['|', '-', 'L', 'J', '7', 'F']);
+        }
+    }
+    let len = doubled.len();
+    for (k, &pos) in doubled.iter().enumerate() {
+        let dir = |to: Pos| {
+            Dir4::ALL
+                .into_iter()
+                .find(|&d| grid.offset(pos, d) == Some(to))
+                .unwrap()
+        };
+        let mut ends = [
+            dir(doubled[(k + len - 1) % len]),
+            dir(doubled[(k + 1) % len]),
+        ];
+        ends.sort();
+        grid[pos] = match ends {
+            [Dir4::Up, Dir4::Down] => '|',
+            [Dir4::Right, Dir4::Left] => '-',
+            [Dir4::Up, Dir4::Right] => 'L',
+            [Dir4::Up, Dir4::Left] => 'J',
+            [Dir4::Down, Dir4::Left] => '7',
+            [Dir4::Right, Dir4::Down] => 'F',
+            _ => unreachable!("loop doubles back"),
+        };
+    }
+
+    let on_loop: HashSet<Pos> = doubled.iter().copied().collect();
+    let start = *rng.pick(&doubled);
+    grid[start] = 'S';
+    // junk next to `S` could otherwise join it
+    for pos in grid.neighbors4(start).collect::<Vec<_>>() {
+        if !on_loop.contains(&pos) {
+            grid[pos] = '.';
+        }
+    }
+
+    (grid.to_string() + "\n", start)
+}
+
+/// A loop of unit steps round a random tree over some connected part of an
+/// `n` by `n` grid of 2x2 blocks, on the `2n` by `2n` lattice of their
+/// corners. It never touches itself, so it bounds a simple polygon.
+fn tree_cycle(rng: &mut Rng, n: usize) -> Vec<Pos> {
+    // random tree over some connected part of an n x n grid of blocks
+    let mut in_tree = Grid::new(n, n, false);
+    let root = (rng.below(n), rng.below(n));
+    in_tree[root] = true;
+    let mut tree = Vec::new();
+    let mut frontier = in_tree
+        .neighbors4(root)
+        .map(|p| (root, p))
+        .collect::<Vec<_>>();
+    let target = rng.range(1..=n * n);
+    while tree.len() + 1 < target && !frontier.is_empty() {
+        let (from, to) = frontier.swap_remove(rng.below(frontier.len()));
+        if in_tree[to] {
+            continue;
+        }
+        in_tree[to] = true;
+        tree.push((from.min(to), from.max(to)));
+        frontier.extend(
+            in_tree
+                .neighbors4(to)
+                .filter(|&p| !in_tree[p])
+                .map(|p| (to, p)),
+        );
+    }
+
+    // a ring round each block's four tiles, then each tree edge swaps the
+    // facing sides of two rings for a pair of bridges, joining them into one
+    // (ordered collections, so the walk below is the same for a seed)
+    let mut links: BTreeSet<(Pos, Pos)> = BTreeSet::new();
+    let link = |a: Pos, b: Pos| (a.min(b), a.max(b));
+    for ((i, j), _) in in_tree.iter().filter(|(_, &t)| t) {
+        let (r, c) = (2 * i, 2 * j);
+        let ring = [(r, c), (r, c + 1), (r + 1, c + 1), (r + 1, c)];
+        for k in 0..4 {
+            links.insert(link(ring[k], ring[(k + 1) % 4]));
+        }
+    }
+    for ((i, j), (i2, _)) in tree {
+        let (r, c) = (2 * i, 2 * j);
+        let (removed, bridges) = if i2 == i {
+            (
+                [((r, c + 1), (r + 1, c + 1)), ((r, c + 2), (r + 1, c + 2))],
+                [((r, c + 1), (r, c + 2)), ((r + 1, c + 1), (r + 1, c + 2))],
+            )
+        } else {
+            (
+                [((r + 1, c), (r + 1, c + 1)), ((r + 2, c), (r + 2, c + 1))],
+                [((r + 1, c), (r + 2, c)), ((r + 1, c + 1), (r + 2, c + 1))],
+            )
+        };
+        for (a, b) in removed {
+            links.remove(&link(a, b));
+        }
+        for (a, b) in bridges {
+            links.insert(link(a, b));
+        }
+    }
+
+    // walk the cycle
+    let mut next: BTreeMap<Pos, Vec<Pos>> = BTreeMap::new();
+    for &(a, b) in &links {
+        next.entry(a).or_default().push(b);
+        next.entry(b).or_default().push(a);
+    }
+    let first = *next.keys().min().unwrap();
+    let mut cycle = vec![first];
+    let mut prev = first;
+    let mut curr = next[&first][0];
+    while curr != first {
+        cycle.push(curr);
+        let step = *next[&curr].iter().find(|&&p| p != prev).unwrap();
+        (prev, curr) = (curr, step);
+    }
+    cycle
+}
+
+/// A `size` by `size` grid (at least 1) with each tile drawn by `tile`.
+fn random_grid(rng: &mut Rng, size: usize, mut tile: impl FnMut(&mut Rng) -> char) -> String {
+    let size = size.max(1);
+    let mut grid = Grid::new(size, size, '.');
+    for pos in grid.positions().collect::<Vec<_>>() {
+        grid[pos] = tile(rng);
+    }
+    grid.to_string() + "\n"
+}
+
+/// A `size` by `size` image of scattered galaxies, with some rows and
+/// columns left empty to expand.
+pub fn day11(rng: &mut Rng, size: usize) -> String {
+    let size = size.max(1);
+    let empty_rows = (0..size).map(|_| rng.chance(0.1)).collect::<Vec<_>>();
+    let empty_cols = (0..size).map(|_| rng.chance(0.1)).collect::<Vec<_>>();
+
+    let mut grid = Grid::new(size, size, '.');
+    for (r, c) in grid.positions().collect::<Vec<_>>() {
+        if !empty_rows[r] && !empty_cols[c] && rng.chance(0.05) {
+            grid[(r, c)] = '#';
+        }
+    }
+    grid.to_string() + "\n"
+}
+
+/// `rows` condition records of 1 to `len` springs, each with at least one
+/// damaged spring and at least one arrangement.
+pub fn day12(rng: &mut Rng, rows: usize, len: usize) -> String {
+    let mut out = String::new();
+    for _ in 0..rows {
+        let mut springs = (0..rng.range(1..=len.max(1)))
+            .map(|_| rng.chance(0.5))
+            .collect::<Vec<_>>();
+        if !springs.contains(&true) {
+            let i = rng.below(springs.len());
+            springs[i] = true;
+        }
+
+        let groups = springs
+            .split(|&damaged| !damaged)
+            .filter(|run| !run.is_empty())
+            .map(|run| run.len().to_string())
+            .collect::<Vec<_>>();
+        let record = springs
+            .iter()
+            .map(|&damaged| match (rng.chance(0.5), damaged) {
+                (true, _) => '?',
+                (false, true) => '#',
+                (false, false) => '.',
+            })
+            .collect::<String>();
+
+        let _ = writeln!(out, "{record} {}", groups.join(","));
+    }
+    out
+}
+
+/// `patterns` patterns of 5 to 17 rows and columns, each with one line of
+/// reflection, and one other line that a single smudge away from being
+/// another; no other line is within one smudge.
+pub fn day13(rng: &mut Rng, patterns: usize) -> String {
+    (0..patterns)
+        .map(|_| loop {
+            let (rows, cols) = (rng.range(5..=17), rng.range(5..=17));
+            if let Some(pattern) = mirrored(rng, rows, cols) {
+                break pattern.to_string() + "\n";
+            }
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// A line of reflection in a day 13 pattern: whether it runs between rows,
+/// and how many rows (or columns) are above (or left of) it.
+type Mirror = (bool, usize);
+
+/// The pairs of tiles a mirror maps onto each other.
+fn mirror_pairs(rows: usize, cols: usize, (across_rows, i): Mirror) -> Vec<(Pos, Pos)> {
+    let (len, width) = if across_rows {
+        (rows, cols)
+    } else {
+        (cols, rows)
+    };
+    let mut pairs = Vec::new();
+    for k in 0..i.min(len - i) {
+        for j in 0..width {
+            pairs.push(match across_rows {
+                true => ((i - 1 - k, j), (i + k, j)),
+                false => ((j, i - 1 - k), (j, i + k)),
+            });
+        }
+    }
+    pairs
+}
+
+/// A pattern for [`day13`], or `None` when the random one drawn doesn't
+/// qualify.
+fn mirrored(rng: &mut Rng, rows: usize, cols: usize) -> Option<Grid<char>> {
+    let mirrors = (1..rows)
+        .map(|i| (true, i))
+        .chain((1..cols).map(|i| (false, i)))
+        .collect::<Vec<_>>();
+    let old = *rng.pick(&mirrors);
+    let new = *rng.pick(&mirrors);
+    if new == old {
+        return None;
+    }
+
+    // tiles that must match, as classes of a union-find; one pair across
+    // the new mirror is the smudge, and must differ instead
+    let mut parent = (0..rows * cols).collect::<Vec<_>>();
+    fn root(parent: &mut [usize], i: usize) -> usize {
+        let up = parent[i];
+        if up == i {
+            return i;
+        }
+        let top = root(parent, up);
+        parent[i] = top;
+        top
+    }
+    let index = |(r, c): Pos| r * cols + c;
+    let mut new_pairs = mirror_pairs(rows, cols, new);
+    let (p, q) = new_pairs.swap_remove(rng.below(new_pairs.len()));
+    for (a, b) in mirror_pairs(rows, cols, old).into_iter().chain(new_pairs) {
+        let (a, b) = (root(&mut parent, index(a)), root(&mut parent, index(b)));
+        parent[a] = b;
+    }
+    let (p, q) = (root(&mut parent, index(p)), root(&mut parent, index(q)));
+    if p == q {
+        return None;
+    }
+    let mut rock = (0..rows * cols)
+        .map(|_| rng.chance(0.5))
+        .collect::<Vec<_>>();
+    rock[q] = !rock[p];
+
+    let mut grid = Grid::new(rows, cols, '.');
+    for pos in grid.positions().collect::<Vec<_>>() {
+        if rock[root(&mut parent, index(pos))] {
+            grid[pos] = '#';
+        }
+    }
+
+    // a smudge changes how many pairs differ by one for the mirrors it
+    // lies across, so only `old` may match and only `new` may be one off
+    for mirror in mirrors {
+        let smudges = mirror_pairs(rows, cols, mirror)
+            .into_iter()
+            .filter(|&(a, b)| grid[a] != grid[b])
+            .count();
+        let expected = match mirror {
+            m if m == old => 0,
+            m if m == new => 1,
+            _ => 2,
+        };
+        if smudges.min(2) != expected {
+            return None;
+        }
+    }
+    Some(grid)
+}
+
+/// A `size` by `size` platform of round and cube-shaped rocks.
+pub fn day14(rng: &mut Rng, size: usize) -> String {
+    random_grid(rng, size, |rng| match rng.below(10) {
+        0 | 1 => 'O',
+        2 => '#',
+        _ => '.',
+    })
+}
+
+/// An initialization sequence of `steps` steps over about a quarter as many
+/// labels, so most are set and removed more than once.
+pub fn day15(rng: &mut Rng, steps: usize) -> String {
+    let labels = (0..steps / 4 + 1)
+        .map(|_| {
+            (0..rng.range(2..=6))
+                .map(|_| rng.lowercase())
+                .collect::<String>()
+        })
+        .collect::<Vec<_>>();
+    let steps = (0..steps.max(1))
+        .map(|_| {
+            let label = rng.pick(&labels);
+            match rng.chance(0.3) {
+                true => format!("{label}-"),
+                false => format!("{label}={}", rng.range(1..=9)),
+            }
+        })
+        .collect::<Vec<_>>();
+    steps.join(",") + "\n"
+}
+
+/// A `size` by `size` contraption of mirrors and splitters.
+pub fn day16(rng: &mut Rng, size: usize) -> String {
+    random_grid(rng, size, |rng| match rng.chance(0.85) {
+        true => '.',
+        false => *rng.pick(&['|', '-', '/', '\\']),
+    })
+}
+
+/// A `size` by `size` map of heat losses (at least 5 across, so the ultra
+/// crucibles can reach the end).
+pub fn day17(rng: &mut Rng, size: usize) -> String {
+    random_grid(rng, size.max(5), |rng| {
+        char::from(b'1' + rng.below(9) as u8)
+    })
+}
+
+/// A dig plan round a lagoon whose outline never touches itself, as in the
+/// puzzle, turning at every step. The outline goes round a random tree of
+/// blocks on a `size` by `size` grid, stretched by short distances for the
+/// plain plan and long ones for the colours, so both read as valid plans.
+pub fn day18(rng: &mut Rng, size: usize) -> String {
+    let n = size.max(1);
+    let cycle = tree_cycle(rng, n);
+
+    // where each line of the lattice ends up, for either plan
+    let mut stretch = |most: usize| {
+        let mut at = 0;
+        (0..2 * n)
+            .map(|_| {
+                at += rng.range(1..=most);
+                at
+            })
+            .collect::<Vec<_>>()
+    };
+    let (rows, cols) = (stretch(10), stretch(10));
+    let (far_rows, far_cols) = (stretch(0xfffff / (2 * n)), stretch(0xfffff / (2 * n)));
+
+    // runs of steps the same way are one dig
+    let corners = cycle
+        .iter()
+        .zip(cycle.iter().cycle().skip(1))
+        .zip(cycle.iter().cycle().skip(2))
+        .filter(|((a, b), c)| a.0 + c.0 != 2 * b.0 || a.1 + c.1 != 2 * b.1)
+        .map(|((_, &b), _)| b)
+        .collect::<Vec<_>>();
+
+    let mut out = String::new();
+    for (&(r, c), &(r2, c2)) in corners.iter().zip(corners.iter().cycle().skip(1)) {
+        // directions numbered as in the colours: R, D, L, U
+        let (dir, from, to) = match (r == r2, c < c2, r < r2) {
+            (true, true, _) => (0, c, c2),
+            (false, _, true) => (1, r, r2),
+            (true, false, _) => (2, c, c2),
+            (false, _, false) => (3, r, r2),
+        };
+        let (near, far) = match dir % 2 {
+            0 => (&cols, &far_cols),
+            _ => (&rows, &far_rows),
+        };
+        let dist = |lines: &[usize]| lines[from].abs_diff(lines[to]);
+        let _ = writeln!(
+            out,
+            "{} {} (#{:05x}{dir})",
+            &"RDLU"[dir..=dir],
+            dist(near),
+            dist(far)
+        );
+    }
+    out
+}
+
+/// `workflows` workflows starting from `in`, each sending only to later
+/// ones (so there are no cycles) or to `A`/`R`, and `parts` parts.
+pub fn day19(rng: &mut Rng, workflows: usize, parts: usize) -> String {
+    let workflows = workflows.max(1);
+    let mut names = vec!["in".to_string()];
+    names.extend(rng.names(workflows - 1, &["in"]));
+
+    let mut out = String::new();
+    for (i, name) in names.iter().enumerate() {
+        let target = |rng: &mut Rng| {
+            if i + 1 < names.len() && rng.chance(0.6) {
+                names[rng.range(i + 1..=names.len() - 1)].clone()
+            } else {
+                rng.pick(&["A", "R"]).to_string()
+            }
+        };
+        let rules = (0..rng.range(1..=4))
+            .map(|_| {
+                let category = rng.pick(&["x", "m", "a", "s"]);
+                let op = rng.pick(&["<", ">"]);
+                let value = rng.range(1..=4000);
+                format!("{category}{op}{value}:{}", target(rng))
+            })
+            .collect::<Vec<_>>();
+        let _ = writeln!(out, "{name}{{{},{}}}", rules.join(","), target(rng));
+    }
+
+    out.push('\n');
+    for _ in 0..parts {
+        let [x, m, a, s] = [(); 4].map(|_| rng.range(1..=4000));
+        let _ = writeln!(out, "{{x={x},m={m},a={a},s={s}}}");
+    }
+    out
+}
+
+/// A network shaped like the real ones: the broadcaster drives `counters`
+/// binary counters of `bits` flip-flops (at least 2), each resetting at a
+/// random period through its own conjunction, and `rx` fires once every
+/// counter resets on the same press. Also gives that press, the lcm of the
+/// periods; counters beyond what keeps it within a `u64` are left out.
+pub fn day20(rng: &mut Rng, counters: usize, bits: usize) -> (String, u64) {
+    let bits = bits.clamp(2, 32);
+    let counters = counters.clamp(1, 64 / bits);
+    let mut names = rng.names(counters * (bits + 2) + 1, &["rx"]).into_iter();
+    let last = names.next().unwrap();
+
+    let mut lines = Vec::new();
+    let mut starts = Vec::new();
+    let mut presses = 1;
+    for _ in 0..counters {
+        // odd, so the lowest bit is wired both ways, and with the top bit set
+        let period = (1 << (bits - 1)) | rng.below(1 << (bits - 1)) | 1;
+        presses = num::integer::lcm(presses, period as u64);
+
+        let flip_flops = names.by_ref().take(bits).collect::<Vec<_>>();
+        let (hub, inverter) = (names.next().unwrap(), names.next().unwrap());
+
+        // counting up, set bits report to the hub; on the period it sends
+        // low to the unset bits and the lowest, which wraps the count to 0
+        let mut resets = vec![flip_flops[0].clone()];
+        for (bit, ff) in flip_flops.iter().enumerate() {
+            let mut dests = Vec::new();
+            if let Some(next) = flip_flops.get(bit + 1) {
+                dests.push(next.clone());
+            }
+            if period >> bit & 1 == 1 {
+                dests.push(hub.clone());
+            } else {
+                resets.push(ff.clone());
+            }
+            rng.shuffle(&mut dests);
+            lines.push(format!("%{ff} -> {}", dests.join(", ")));
+        }
+        resets.push(inverter.clone());
+        rng.shuffle(&mut resets);
+        lines.push(format!("&{hub} -> {}", resets.join(", ")));
+        lines.push(format!("&{inverter} -> {last}"));
+        starts.push(flip_flops[0].clone());
+    }
+    lines.push(format!("&{last} -> rx"));
+    lines.push(format!("broadcaster -> {}", starts.join(", ")));
+    rng.shuffle(&mut lines);
+
+    (lines.join("\n") + "\n", presses)
+}
+
+/// A square garden of the first side in 3, 131 (the puzzle's) and 393 that
+/// is at least `size`, with `S` in the centre, its row and column clear, and
+/// rocks scattered elsewhere, so part 2's extrapolation applies.
+pub fn day21(rng: &mut Rng, size: usize) -> String {
+    let side = [3, 131, 393]
+        .into_iter()
+        .find(|&side| side >= size)
+        .unwrap_or(393);
+    let mid = side / 2;
+
+    let mut grid = Grid::new(side, side, '.');
+    for (r, c) in grid.positions().collect::<Vec<_>>() {
+        if r != mid && c != mid && rng.chance(0.15) {
+            grid[(r, c)] = '#';
+        }
+    }
+    grid[(mid, mid)] = 'S';
+    grid.to_string() + "\n"
+}
+
+/// `bricks` bricks of 1 to 4 cubes over a 10 by 10 floor, none overlapping,
+/// in no particular order.
+pub fn day22(rng: &mut Rng, bricks: usize) -> String {
+    let mut filled = HashSet::new();
+    let mut lines = Vec::new();
+    let mut top = 10;
+
+    while lines.len() < bricks {
+        let axis = rng.below(3);
+        let extra = rng.below(4);
+        let mut lower = [rng.below(10), rng.below(10), rng.range(1..=top)];
+        lower[axis] = lower[axis].min(if axis == 2 { usize::MAX } else { 9 - extra });
+        let cubes = (0..=extra)
+            .map(|k| {
+                let mut cube = lower;
+                cube[axis] += k;
+                cube
+            })
+            .collect::<Vec<_>>();
+
+        if cubes.iter().any(|cube| filled.contains(cube)) {
+            // crowded: make room higher up
+            top += 1;
+            continue;
+        }
+        filled.extend(cubes.iter().copied());
+        let upper = cubes[extra];
+        lines.push(format!(
+            "{},{},{}~{},{},{}",
+            lower[0], lower[1], lower[2], upper[0], upper[1], upper[2]
+        ));
+    }
+
+    lines.join("\n") + "\n"
+}
+
+/// A maze of trails like the puzzle's: a `size` by `size` lattice of
+/// junctions (1 to 6 across) joined by trails down and to the right, with
+/// slopes at both ends of each so the one-way maze has no cycles. Trails
+/// to the right may dip down in a U on the way.
+pub fn day23(rng: &mut Rng, size: usize) -> String {
+    let k = size.clamp(1, 6);
+    let mut junction_rows = vec![rng.range(2..=5)];
+    for _ in 0..k {
+        junction_rows.push(junction_rows.last().unwrap() + rng.range(4..=10));
+    }
+    // the last is the bottom row
+    let rows = junction_rows.pop().unwrap() + 1;
+    let mut junction_cols = vec![1];
+    for _ in 1..k {
+        junction_cols.push(junction_cols.last().unwrap() + rng.range(6..=14));
+    }
+    let cols = junction_cols.last().unwrap() + 2;
+
+    let mut grid = Grid::new(rows, cols, '#');
+    for r in 0..=junction_rows[0] {
+        grid[(r, 1)] = '.';
+    }
+    for r in junction_rows[k - 1]..rows {
+        grid[(r, cols - 2)] = '.';
+    }
+    for (i, &r) in junction_rows.iter().enumerate() {
+        let below = junction_rows.get(i + 1).copied();
+        for (j, &c) in junction_cols.iter().enumerate() {
+            if let Some(r2) = below {
+                for row in r..r2 {
+                    grid[(row, c)] = '.';
+                }
+                grid[(r + 1, c)] = 'v';
+                grid[(r2 - 1, c)] = 'v';
+            }
+            let Some(&c2) = junction_cols.get(j + 1) else {
+                continue;
+            };
+            for col in c..c2 {
+                grid[(r, col)] = '.';
+            }
+            grid[(r, c + 1)] = '>';
+            grid[(r, c2 - 1)] = '>';
+
+            // keep a row of rock between the dip and whatever is below
+            let deepest = below.unwrap_or(rows - 1) - r - 2;
+            if rng.chance(0.5) {
+                let depth = rng.range(1..=deepest);
+                let a = rng.range(c + 2..=c2 - 4);
+                let b = rng.range(a + 2..=c2 - 2);
+                for col in a + 1..b {
+                    grid[(r, col)] = '#';
+                    grid[(r + depth, col)] = '.';
+                }
+                for row in r..=r + depth {
+                    grid[(row, a)] = '.';
+                    grid[(row, b)] = '.';
+                }
+            }
+        }
+    }
+    grid.to_string() + "\n"
+}
+
+/// `hails` hailstones (at least 3) whose paths a rock thrown from a whole
+/// position with a whole velocity hits, each at its own time, with the
+/// hailstones starting near the puzzle's test area. Also gives the sum of
+/// the rock's coordinates.
+pub fn day24(rng: &mut Rng, hails: usize) -> (String, u64) {
+    const AREA: (i64, i64) = (200_000_000_000_000, 400_000_000_000_000);
+    let speed = |rng: &mut Rng| rng.range(0..=600) as i64 - 300;
+    let rock = [(); 3].map(|_| rng.range(250_000_000_000_000..=349_999_999_999_999) as i64);
+    let rock_v = [(); 3].map(|_| speed(rng));
+
+    let mut times = HashSet::new();
+    let mut lines = Vec::new();
+    while lines.len() < hails.max(3) {
+        let t = rng.range(100_000_000_000..=1_000_000_000_000) as i64;
+        let v = [(); 3].map(|_| speed(rng));
+        if v.contains(&0) || v == rock_v || times.contains(&t) {
+            continue;
+        }
+        let pos = [0, 1, 2].map(|i| rock[i] + (rock_v[i] - v[i]) * t);
+        let inside = |x: i64| AREA.0 < x && x < AREA.1;
+        if inside(pos[0]) && inside(pos[1]) && pos[2] > 0 {
+            times.insert(t);
+            lines.push(format!(
+                "{}, {}, {} @ {}, {}, {}",
+                pos[0], pos[1], pos[2], v[0], v[1], v[2]
+            ));
+        }
+    }
+
+    (lines.join("\n") + "\n", rock.iter().sum::<i64>() as u64)
+}
+
+/// A wiring diagram of `nodes` components (at least 10) in two groups, each
+/// too well connected inside to split with three cuts, joined by three
+/// wires. Also gives the product of the groups' sizes.
+pub fn day25(rng: &mut Rng, nodes: usize) -> (String, usize) {
+    let nodes = nodes.max(10);
+    let left = rng.range(5..=nodes - 5);
+    let names = rng.names(nodes, &[]);
+
+    let mut edges = BTreeSet::new();
+    let mut wire = |a: usize, b: usize| {
+        if a != b {
+            edges.insert((a.min(b), a.max(b)));
+        }
+    };
+    for (from, len) in [(0, left), (left, nodes - left)] {
+        // each linked to the next two round a ring, which takes four cuts to
+        // split, plus a few more at random
+        for i in 0..len {
+            wire(from + i, from + (i + 1) % len);
+            wire(from + i, from + (i + 2) % len);
+            for _ in 0..rng.range(1..=3) {
+                wire(from + i, from + rng.below(len));
+            }
+        }
+    }
+    let mut lefts = (0..left).collect::<Vec<_>>();
+    let mut rights = (left..nodes).collect::<Vec<_>>();
+    rng.shuffle(&mut lefts);
+    rng.shuffle(&mut rights);
+    for (&a, &b) in lefts.iter().zip(&rights).take(3) {
+        wire(a, b);
+    }
+
+    // each wire listed once, under either end
+    let mut listed: BTreeMap<usize, Vec<&str>> = BTreeMap::new();
+    for (a, b) in edges {
+        let (under, other) = if rng.chance(0.5) { (a, b) } else { (b, a) };
+        listed.entry(under).or_default().push(&names[other]);
+    }
+    let mut lines = listed
+        .into_iter()
+        .map(|(node, others)| format!("{}: {}", names[node], others.join(" ")))
+        .collect::<Vec<_>>();
+    rng.shuffle(&mut lines);
+
+    (lines.join("\n") + "\n", left * (nodes - left))
+}
+
+#[test]
+fn seeds_reproduce() {
+    for day in 1..=25 {
+        let a = input(day, &mut Rng::new(7), 6).unwrap();
+        let b = input(day, &mut Rng::new(7), 6).unwrap();
+        let c = input(day, &mut Rng::new(8), 6).unwrap();
+        assert_eq!(a, b, "day {day}");
+        assert_ne!(a, c, "day {day}");
+    }
+    assert_eq!(input(26, &mut Rng::new(7), 6), None);
+}
+
+#[test]
+fn generated_inputs_solve() {
+    use crate::days;
+
+    for seed in 0..20 {
+        let mut rng = Rng::new(seed);
+        // day 20 below, with counters short enough to run quickly
+        for day in (1..=25).filter(|&day| day != 20) {
+            let input = input(day, &mut rng, 3).unwrap();
+            let solution = days::get(day).unwrap();
+            let run = solution.run(&input, &[1, 2]);
+            assert!(
+                matches!(run.parse.value, Some(Ok(()))),
+                "day {day} seed {seed}"
+            );
+            assert!(
+                run.answers.iter().all(|(_, a)| a.value.is_some()),
+                "day {day} seed {seed}:\n{input}"
+            );
+            let broken = solution
+                .check(&input)
+                .unwrap()
+                .into_iter()
+                .filter(|a| !a.holds)
+                .collect::<Vec<_>>();
+            assert!(broken.is_empty(), "day {day} seed {seed}: {broken:?}");
+        }
+
+        let (network, steps) = day08(&mut rng, 4, 5);
+        let run = days::get(8).unwrap().run(&network, &[2]);
+        assert_eq!(
+            run.answers[0].1.value,
+            Some(steps.to_string()),
+            "seed {seed}"
+        );
+        let (hail, sum) = day24(&mut rng, 5);
+        let run = days::get(24).unwrap().run(&hail, &[2]);
+        assert_eq!(run.answers[0].1.value, Some(sum.to_string()), "seed {seed}");
+        let (wiring, product) = day25(&mut rng, 30);
+        let run = days::get(25).unwrap().run(&wiring, &[1]);
+        assert_eq!(
+            run.answers[0].1.value,
+            Some(product.to_string()),
+            "seed {seed}"
+        );
+
+        let (network, presses) = day20(&mut rng, 3, 6);
+        let run = days::get(20).unwrap().run(&network, &[2]);
+        assert_eq!(
+            run.answers[0].1.value,
+            Some(presses.to_string()),
+            "seed {seed}"
+        );
+    }
+}
+
+#[test]
+fn single_loop() {
+    for seed in 0..30 {
+        let mut rng = Rng::new(seed);
+        let size = rng.range(3..=30);
+        let (maze, start) = day10(&mut rng, size);
+        let grid = crate::parse::char_grid(&maze, "|-LJ7F.S").unwrap();
+        assert_eq!((grid.rows(), grid.cols()), (size, size));
+        assert_eq!(grid.find(&'S'), Some(start));
+        let grid = &grid;
+
+        // pipes joining both ways, the same test the solution uses
+        let opens = |pos: Pos, d: Dir4| {
+            matches!(
+                (grid[pos], d),
+                ('S', _)
+                    | ('|', Dir4::Up | Dir4::Down)
+                    | ('-', Dir4::Left | Dir4::Right)
+                    | ('L', Dir4::Up | Dir4::Right)
+                    | ('J', Dir4::Up | Dir4::Left)
+                    | ('7', Dir4::Down | Dir4::Left)
+                    | ('F', Dir4::Down | Dir4::Right)
+            )
+        };
+        let joined = |pos: Pos| {
+            Dir4::ALL
+                .into_iter()
+                .filter_map(move |d| {
+                    let next = grid.offset(pos, d)?;
+                    (opens(pos, d) && opens(next, d.opposite())).then_some(next)
+                })
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(joined(start).len(), 2, "seed {seed}\n{maze}");
+        let (mut prev, mut curr, mut len) = (start, joined(start)[0], 1);
+        while curr != start {
+            let next = joined(curr);
+            assert_eq!(next.len(), 2, "seed {seed} at {curr:?}\n{maze}");
+            (prev, curr) = (curr, *next.iter().find(|&&p| p != prev).unwrap());
+            len += 1;
+        }
+        assert!(len >= 8 && len % 2 == 0, "seed {seed}");
+    }
+}
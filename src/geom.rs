@@ -0,0 +1,292 @@
+//! Directions and coordinates shared by the grid puzzles. Rows grow
+//! downwards, so `Up` is `(-1, 0)` as `(row, col)`.
+
+use std::ops::{Add, AddAssign, Mul, Neg, Sub};
+
+use crate::grid::Pos;
+
+/// Signed `(row, col)` offset.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, PartialOrd, Ord)]
+pub struct Vec2 {
+    pub r: isize,
+    pub c: isize,
+}
+
+/// Point on an unbounded plane, `(row, col)`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, PartialOrd, Ord)]
+pub struct Point {
+    pub r: isize,
+    pub c: isize,
+}
+
+impl Vec2 {
+    pub const fn new(r: isize, c: isize) -> Self {
+        Self { r, c }
+    }
+
+    pub fn manhattan(self) -> usize {
+        self.r.unsigned_abs() + self.c.unsigned_abs()
+    }
+}
+
+impl Point {
+    pub const fn new(r: isize, c: isize) -> Self {
+        Self { r, c }
+    }
+
+    /// `None` if either coordinate is negative.
+    pub fn to_pos(self) -> Option<Pos> {
+        Some((usize::try_from(self.r).ok()?, usize::try_from(self.c).ok()?))
+    }
+}
+
+impl From<(isize, isize)> for Vec2 {
+    fn from((r, c): (isize, isize)) -> Self {
+        Self { r, c }
+    }
+}
+
+impl From<Pos> for Point {
+    fn from((r, c): Pos) -> Self {
+        Self::new(r as isize, c as isize)
+    }
+}
+
+impl Add<Vec2> for Point {
+    type Output = Point;
+
+    fn add(self, v: Vec2) -> Point {
+        Point::new(self.r + v.r, self.c + v.c)
+    }
+}
+
+impl AddAssign<Vec2> for Point {
+    fn add_assign(&mut self, v: Vec2) {
+        *self = *self + v;
+    }
+}
+
+impl Sub for Point {
+    type Output = Vec2;
+
+    fn sub(self, other: Point) -> Vec2 {
+        Vec2::new(self.r - other.r, self.c - other.c)
+    }
+}
+
+impl Add for Vec2 {
+    type Output = Vec2;
+
+    fn add(self, v: Vec2) -> Vec2 {
+        Vec2::new(self.r + v.r, self.c + v.c)
+    }
+}
+
+impl Mul<isize> for Vec2 {
+    type Output = Vec2;
+
+    fn mul(self, k: isize) -> Vec2 {
+        Vec2::new(self.r * k, self.c * k)
+    }
+}
+
+impl Neg for Vec2 {
+    type Output = Vec2;
+
+    fn neg(self) -> Vec2 {
+        Vec2::new(-self.r, -self.c)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Dir4 {
+    Up,
+    Right,
+    Down,
+    Left,
+}
+
+impl Dir4 {
+    /// clockwise from `Up`
+    pub const ALL: [Dir4; 4] = [Self::Up, Self::Right, Self::Down, Self::Left];
+
+    pub fn delta(self) -> Vec2 {
+        match self {
+            Self::Up => Vec2::new(-1, 0),
+            Self::Right => Vec2::new(0, 1),
+            Self::Down => Vec2::new(1, 0),
+            Self::Left => Vec2::new(0, -1),
+        }
+    }
+
+    pub fn turn_right(self) -> Self {
+        Self::ALL[(self as usize + 1) % 4]
+    }
+
+    pub fn turn_left(self) -> Self {
+        Self::ALL[(self as usize + 3) % 4]
+    }
+
+    pub fn opposite(self) -> Self {
+        Self::ALL[(self as usize + 2) % 4]
+    }
+
+    pub fn is_vertical(self) -> bool {
+        matches!(self, Self::Up | Self::Down)
+    }
+
+    /// off a `/` mirror
+    pub fn reflect_forward(self) -> Self {
+        match self {
+            Self::Up => Self::Right,
+            Self::Down => Self::Left,
+            Self::Left => Self::Down,
+            Self::Right => Self::Up,
+        }
+    }
+
+    /// off a `\` mirror
+    pub fn reflect_backward(self) -> Self {
+        match self {
+            Self::Up => Self::Left,
+            Self::Down => Self::Right,
+            Self::Left => Self::Up,
+            Self::Right => Self::Down,
+        }
+    }
+
+    /// `U`/`R`/`D`/`L` or the compass letters `N`/`E`/`S`/`W`
+    pub fn from_letter(ch: char) -> Option<Self> {
+        match ch {
+            'U' | 'N' => Some(Self::Up),
+            'R' | 'E' => Some(Self::Right),
+            'D' | 'S' => Some(Self::Down),
+            'L' | 'W' => Some(Self::Left),
+            _ => None,
+        }
+    }
+
+    /// `^`, `>`, `v` or `<`
+    pub fn from_arrow(ch: char) -> Option<Self> {
+        match ch {
+            '^' => Some(Self::Up),
+            '>' => Some(Self::Right),
+            'v' => Some(Self::Down),
+            '<' => Some(Self::Left),
+            _ => None,
+        }
+    }
+
+    /// `0`-`3` for right, down, left, up (day 18's colour codes)
+    pub fn from_hex_digit(ch: char) -> Option<Self> {
+        match ch {
+            '0' => Some(Self::Right),
+            '1' => Some(Self::Down),
+            '2' => Some(Self::Left),
+            '3' => Some(Self::Up),
+            _ => None,
+        }
+    }
+
+    /// `pos` moved `dist` steps, unless that leaves the non-negative quadrant.
+    pub fn step(self, pos: Pos, dist: usize) -> Option<Pos> {
+        let pos = Point::from(pos) + self.delta() * dist as isize;
+        pos.to_pos()
+    }
+}
+
+impl From<Dir4> for Vec2 {
+    fn from(dir: Dir4) -> Self {
+        dir.delta()
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Dir8 {
+    N,
+    NE,
+    E,
+    SE,
+    S,
+    SW,
+    W,
+    NW,
+}
+
+impl Dir8 {
+    /// clockwise from `N`
+    pub const ALL: [Dir8; 8] = [
+        Self::N,
+        Self::NE,
+        Self::E,
+        Self::SE,
+        Self::S,
+        Self::SW,
+        Self::W,
+        Self::NW,
+    ];
+
+    pub fn delta(self) -> Vec2 {
+        match self {
+            Self::N => Vec2::new(-1, 0),
+            Self::NE => Vec2::new(-1, 1),
+            Self::E => Vec2::new(0, 1),
+            Self::SE => Vec2::new(1, 1),
+            Self::S => Vec2::new(1, 0),
+            Self::SW => Vec2::new(1, -1),
+            Self::W => Vec2::new(0, -1),
+            Self::NW => Vec2::new(-1, -1),
+        }
+    }
+
+    /// 45 degrees clockwise
+    pub fn turn_right(self) -> Self {
+        Self::ALL[(self as usize + 1) % 8]
+    }
+
+    /// 45 degrees anticlockwise
+    pub fn turn_left(self) -> Self {
+        Self::ALL[(self as usize + 7) % 8]
+    }
+
+    pub fn opposite(self) -> Self {
+        Self::ALL[(self as usize + 4) % 8]
+    }
+}
+
+impl From<Dir4> for Dir8 {
+    fn from(dir: Dir4) -> Self {
+        Self::ALL[dir as usize * 2]
+    }
+}
+
+impl From<Dir8> for Vec2 {
+    fn from(dir: Dir8) -> Self {
+        dir.delta()
+    }
+}
+
+#[test]
+fn turns() {
+    for dir in Dir4::ALL {
+        assert_eq!(dir.turn_left().turn_right(), dir);
+        assert_eq!(dir.opposite().delta(), -dir.delta());
+        assert_eq!(dir.reflect_forward().reflect_forward(), dir);
+        assert_eq!(Dir8::from(dir).delta(), dir.delta());
+        assert_eq!(Dir8::from(dir).opposite(), Dir8::from(dir.opposite()));
+    }
+    assert_eq!(Dir4::Up.turn_right(), Dir4::Right);
+    assert_eq!(Dir8::N.turn_left(), Dir8::NW);
+    assert_eq!(Dir4::Right.reflect_backward(), Dir4::Down);
+}
+
+#[test]
+fn parse_and_step() {
+    assert_eq!(Dir4::from_letter('N'), Dir4::from_arrow('^'));
+    assert_eq!(Dir4::from_hex_digit('1'), Some(Dir4::Down));
+    assert_eq!(Dir4::from_letter('x'), None);
+
+    assert_eq!(Dir4::Left.step((3, 2), 2), Some((3, 0)));
+    assert_eq!(Dir4::Left.step((3, 2), 3), None);
+    assert_eq!(Point::new(1, 1) + Dir4::Up.delta() * 4, Point::new(-3, 1));
+}
@@ -0,0 +1,399 @@
+//! Searches over implicit graphs. A graph is just a successor function from
+//! a state to its neighbours, so grid positions, `(pos, dir)` pairs and
+//! bitmasks all work without building anything up front.
+
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashMap, VecDeque};
+use std::hash::Hash;
+use std::ops::Add;
+
+use crate::stats;
+
+/// Edge weights: anything that sums and orders, with `Default` as zero.
+pub trait Cost: Copy + Ord + Default + Add<Output = Self> {}
+
+impl<C: Copy + Ord + Default + Add<Output = C>> Cost for C {}
+
+/// Explicit weighted graph, e.g. the result of [`contract`].
+pub type Graph<S, C> = HashMap<S, Vec<(S, C)>>;
+
+/// Every state a [`bfs`] reached, with its distance and how it got there.
+#[derive(Debug, Clone)]
+pub struct Reached<S> {
+    dist: HashMap<S, usize>,
+    parent: HashMap<S, S>,
+}
+
+impl<S: Clone + Eq + Hash> Reached<S> {
+    pub fn distance(&self, state: &S) -> Option<usize> {
+        self.dist.get(state).copied()
+    }
+
+    /// Shortest path from the start to `state`, both included.
+    pub fn path_to(&self, state: &S) -> Option<Vec<S>> {
+        self.dist.get(state)?;
+        let mut path = vec![state.clone()];
+        while let Some(prev) = self.parent.get(path.last().unwrap()) {
+            path.push(prev.clone());
+        }
+        path.reverse();
+        Some(path)
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (&S, usize)> {
+        self.dist.iter().map(|(s, &d)| (s, d))
+    }
+
+    pub fn len(&self) -> usize {
+        self.dist.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.dist.is_empty()
+    }
+}
+
+/// Unweighted breadth-first search from `start` over everything reachable.
+pub fn bfs<S, I>(start: S, mut successors: impl FnMut(&S) -> I) -> Reached<S>
+where
+    S: Clone + Eq + Hash,
+    I: IntoIterator<Item = S>,
+{
+    let mut reached = Reached {
+        dist: HashMap::from([(start.clone(), 0)]),
+        parent: HashMap::new(),
+    };
+    let mut queue = VecDeque::from([start]);
+
+    while let Some(state) = queue.pop_front() {
+        let dist = reached.dist[&state] + 1;
+        for next in successors(&state) {
+            if reached.dist.contains_key(&next) {
+                continue;
+            }
+            reached.dist.insert(next.clone(), dist);
+            reached.parent.insert(next.clone(), state.clone());
+            queue.push_back(next);
+        }
+    }
+
+    reached
+}
+
+/// Cheapest path from `start` to the first state satisfying `is_goal`, as
+/// its cost and the states along it.
+pub fn dijkstra<S, C, I>(
+    start: S,
+    successors: impl FnMut(&S) -> I,
+    is_goal: impl FnMut(&S) -> bool,
+) -> Option<(C, Vec<S>)>
+where
+    S: Clone + Eq + Hash,
+    C: Cost,
+    I: IntoIterator<Item = (S, C)>,
+{
+    astar(start, successors, |_| C::default(), is_goal)
+}
+
+/// [`dijkstra`] guided by `heuristic`, which must never overestimate the
+/// remaining cost for the result to be optimal.
+pub fn astar<S, C, I>(
+    start: S,
+    mut successors: impl FnMut(&S) -> I,
+    mut heuristic: impl FnMut(&S) -> C,
+    mut is_goal: impl FnMut(&S) -> bool,
+) -> Option<(C, Vec<S>)>
+where
+    S: Clone + Eq + Hash,
+    C: Cost,
+    I: IntoIterator<Item = (S, C)>,
+{
+    // every state pushed, with the index of its parent; `best` holds the
+    // index and cost of the cheapest entry for each state so stale heap
+    // entries can be told apart
+    let mut states = vec![(start.clone(), usize::MAX)];
+    let mut best = HashMap::from([(start.clone(), (0, C::default()))]);
+    let mut heap = BinaryHeap::from([Reverse((heuristic(&start), C::default(), 0))]);
+
+    while let Some(Reverse((_, cost, i))) = heap.pop() {
+        stats::count("heap_pops");
+        let state = states[i].0.clone();
+        if best[&state].0 != i {
+            continue;
+        }
+        if is_goal(&state) {
+            let mut path = Vec::new();
+            let mut j = i;
+            while j != usize::MAX {
+                path.push(states[j].0.clone());
+                j = states[j].1;
+            }
+            path.reverse();
+            return Some((cost, path));
+        }
+
+        for (next, step) in successors(&state) {
+            let next_cost = cost + step;
+            if best.get(&next).is_some_and(|&(_, c)| c <= next_cost) {
+                continue;
+            }
+            let j = states.len();
+            heap.push(Reverse((next_cost + heuristic(&next), next_cost, j)));
+            stats::count("heap_pushes");
+            best.insert(next.clone(), (j, next_cost));
+            states.push((next, i));
+        }
+    }
+
+    None
+}
+
+/// Collapses the corridors of an undirected graph, leaving the states that
+/// are not simply a step between two others (junctions and dead ends), plus
+/// `start` and any for which `keep` is true. Edges are weighted by the
+/// number of steps along the corridor.
+pub fn contract<S, I>(
+    start: S,
+    mut neighbours: impl FnMut(&S) -> I,
+    mut keep: impl FnMut(&S) -> bool,
+) -> Graph<S, usize>
+where
+    S: Clone + Eq + Hash,
+    I: IntoIterator<Item = S>,
+{
+    let mut graph: Graph<S, usize> = HashMap::new();
+    let mut queue = vec![start.clone()];
+
+    while let Some(node) = queue.pop() {
+        if graph.contains_key(&node) {
+            continue;
+        }
+        let mut edges = Vec::new();
+
+        for first in neighbours(&node) {
+            let (mut prev, mut curr, mut len) = (node.clone(), first, 1);
+            loop {
+                let next: Vec<S> = neighbours(&curr).into_iter().collect();
+                if next.len() != 2 || curr == start || keep(&curr) {
+                    break;
+                }
+                let Some(step) = next.into_iter().find(|s| *s != prev) else {
+                    break;
+                };
+                (prev, curr, len) = (curr, step, len + 1);
+            }
+            queue.push(curr.clone());
+            edges.push((curr, len));
+        }
+
+        graph.insert(node, edges);
+    }
+
+    graph
+}
+
+/// Length of the longest path from `start` to `end` that visits no node
+/// twice, and the nodes along it, by exhaustive search; only practical for
+/// a few dozen nodes.
+///
+/// # Panics
+///
+/// If the graph has more than 128 nodes.
+pub fn longest_path<S, C>(graph: &Graph<S, C>, start: &S, end: &S) -> Option<(C, Vec<S>)>
+where
+    S: Clone + Eq + Hash,
+    C: Cost,
+{
+    let nodes: Vec<&S> = graph.keys().collect();
+    let index: HashMap<&S, usize> = nodes.iter().enumerate().map(|(i, &s)| (s, i)).collect();
+    assert!(index.len() <= 128, "{} nodes is too many", index.len());
+
+    let adjacency: Vec<Vec<(usize, C)>> = {
+        let mut adjacency = vec![Vec::new(); index.len()];
+        for (s, edges) in graph {
+            adjacency[index[s]] = edges
+                .iter()
+                .filter_map(|(t, c)| Some((*index.get(t)?, *c)))
+                .collect();
+        }
+        adjacency
+    };
+
+    struct Search<'a, C> {
+        adjacency: &'a [Vec<(usize, C)>],
+        end: usize,
+        /// the nodes of the path being extended
+        stack: Vec<usize>,
+        best: Option<(C, Vec<usize>)>,
+    }
+
+    impl<C: Cost> Search<'_, C> {
+        fn visit(&mut self, node: usize, seen: u128, cost: C) {
+            stats::count("dfs_visits");
+            self.stack.push(node);
+            if node == self.end {
+                stats::count("paths_found");
+                if self.best.as_ref().is_none_or(|(c, _)| cost > *c) {
+                    self.best = Some((cost, self.stack.clone()));
+                }
+            } else {
+                let seen = seen | 1 << node;
+                for &(next, c) in self.adjacency[node].iter() {
+                    if seen & 1 << next == 0 {
+                        self.visit(next, seen, cost + c);
+                    }
+                }
+            }
+            self.stack.pop();
+        }
+    }
+
+    let mut search = Search {
+        adjacency: &adjacency,
+        end: *index.get(end)?,
+        stack: Vec::new(),
+        best: None,
+    };
+    search.visit(*index.get(start)?, 0, C::default());
+
+    let (cost, path) = search.best?;
+    Some((cost, path.into_iter().map(|i| nodes[i].clone()).collect()))
+}
+
+/// Minimum cut between `source` and `sink` in an undirected multigraph on
+/// nodes `0..nodes` where every edge can carry one unit: the number of
+/// edges cut, and which nodes end up on the source's side. Augments along
+/// [`bfs`] paths (Edmonds–Karp), so it is quick when the cut is small.
+pub fn min_cut(
+    nodes: usize,
+    edges: &[(usize, usize)],
+    source: usize,
+    sink: usize,
+) -> (usize, Vec<bool>) {
+    // residual capacity of each arc, parallel edges adding up
+    let mut residual: Vec<HashMap<usize, usize>> = vec![HashMap::new(); nodes];
+    for &(a, b) in edges {
+        *residual[a].entry(b).or_default() += 1;
+        *residual[b].entry(a).or_default() += 1;
+    }
+
+    let mut flow = 0;
+    loop {
+        let reached = bfs(source, |&u| {
+            residual[u]
+                .iter()
+                .filter(|&(_, &cap)| cap > 0)
+                .map(|(&v, _)| v)
+                .collect::<Vec<_>>()
+        });
+        let Some(path) = reached.path_to(&sink) else {
+            let side = (0..nodes).map(|n| reached.distance(&n).is_some()).collect();
+            return (flow, side);
+        };
+        for step in path.windows(2) {
+            let (u, v) = (step[0], step[1]);
+            *residual[u].get_mut(&v).unwrap() -= 1;
+            *residual[v].entry(u).or_default() += 1;
+        }
+        flow += 1;
+    }
+}
+
+#[cfg(test)]
+fn maze() -> crate::grid::Grid<char> {
+    crate::parse::char_grid(
+        "\
+#.#######
+#.......#
+#.#.###.#
+#.#.....#
+#.#####.#
+#.......#
+#.#######",
+        ".#",
+    )
+    .unwrap()
+}
+
+#[test]
+fn shortest_paths() {
+    let grid = maze();
+    let open = |&pos: &(usize, usize)| {
+        grid.neighbors4(pos)
+            .filter(|&p| grid[p] == '.')
+            .collect::<Vec<_>>()
+    };
+    let (start, end) = ((0, 1), (6, 1));
+
+    let reached = bfs(start, open);
+    assert_eq!(reached.distance(&end), Some(6));
+    let path = reached.path_to(&end).unwrap();
+    assert_eq!((path[0], path[6]), (start, end));
+    assert!(path.windows(2).all(|w| open(&w[0]).contains(&w[1])));
+    assert_eq!(reached.distance(&(3, 7)), Some(9));
+
+    // the short way down the left is made dear, so the cheapest path winds
+    // round through the right-hand side
+    let weighted = |pos: &(usize, usize)| {
+        open(pos)
+            .into_iter()
+            .map(|p| (p, if p == (2, 1) { 20 } else { 1 }))
+            .collect::<Vec<_>>()
+    };
+    let (cost, path) = dijkstra(start, weighted, |&p| p == end).unwrap();
+    assert_eq!((cost, path.len()), (18, 19));
+    assert!(path.contains(&(3, 7)));
+
+    let manhattan = |&(r, c): &(usize, usize)| r.abs_diff(end.0) + c.abs_diff(end.1);
+    let (cost, found) = astar(start, weighted, manhattan, |&p| p == end).unwrap();
+    assert_eq!((cost, found.len()), (18, 19));
+    assert_eq!(dijkstra(start, weighted, |&p| p == (0, 0)), None);
+}
+
+#[test]
+fn corridors() {
+    let grid = maze();
+    let open = |&pos: &(usize, usize)| {
+        grid.neighbors4(pos)
+            .filter(|&p| grid[p] == '.')
+            .collect::<Vec<_>>()
+    };
+    let (start, end) = ((0, 1), (6, 1));
+
+    let graph = contract(start, open, |&p| p == end);
+    let mut nodes = graph.keys().copied().collect::<Vec<_>>();
+    nodes.sort();
+    assert_eq!(nodes, [(0, 1), (1, 1), (1, 3), (3, 7), (5, 1), (6, 1)]);
+    // both corridors between (1, 3) and (3, 7) are kept
+    assert_eq!(graph[&(1, 3)].len(), 3);
+
+    let (len, path) = longest_path(&graph, &start, &end).unwrap();
+    assert_eq!(len, 18);
+    assert_eq!(path, [(0, 1), (1, 1), (1, 3), (3, 7), (5, 1), (6, 1)]);
+    assert_eq!(longest_path(&graph, &start, &(3, 7)).unwrap().0, 13);
+    assert_eq!(longest_path(&graph, &start, &(2, 2)), None);
+}
+
+#[test]
+fn cuts() {
+    // two triangles joined by a doubled edge and a single one
+    let edges = [
+        (0, 1),
+        (1, 2),
+        (2, 0),
+        (3, 4),
+        (4, 5),
+        (5, 3),
+        (2, 3),
+        (2, 3),
+        (0, 5),
+    ];
+    let (cut, side) = min_cut(6, &edges, 2, 3);
+    assert_eq!(cut, 3);
+    assert_eq!(side, [true, true, true, false, false, false]);
+
+    // a node with fewer edges than the bridge is cut off on its own
+    let (cut, side) = min_cut(7, &[edges.as_slice(), &[(6, 1)]].concat(), 6, 4);
+    assert_eq!(cut, 1);
+    assert_eq!(side.iter().filter(|&&s| s).count(), 1);
+}
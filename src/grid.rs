@@ -0,0 +1,265 @@
+//! Rectangular grid stored row-major in one buffer, with the bounds-checked
+//! stepping the 2D puzzles keep needing.
+
+use std::fmt;
+use std::ops::{Index, IndexMut};
+
+use crate::geom::{Dir4, Dir8, Vec2};
+
+/// `(row, col)`
+pub type Pos = (usize, usize);
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    rows: usize,
+    cols: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    pub fn new(rows: usize, cols: usize, fill: T) -> Self
+    where
+        T: Clone,
+    {
+        Self {
+            rows,
+            cols,
+            cells: vec![fill; rows * cols],
+        }
+    }
+
+    /// `None` if the rows differ in length.
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Option<Self> {
+        let cols = rows.first().map_or(0, Vec::len);
+        if rows.iter().any(|row| row.len() != cols) {
+            return None;
+        }
+        Some(Self {
+            rows: rows.len(),
+            cols,
+            cells: rows.into_iter().flatten().collect(),
+        })
+    }
+
+    pub fn rows(&self) -> usize {
+        self.rows
+    }
+
+    pub fn cols(&self) -> usize {
+        self.cols
+    }
+
+    pub fn contains(&self, (r, c): Pos) -> bool {
+        r < self.rows && c < self.cols
+    }
+
+    pub fn get(&self, pos: Pos) -> Option<&T> {
+        self.contains(pos)
+            .then(|| &self.cells[pos.0 * self.cols + pos.1])
+    }
+
+    pub fn get_mut(&mut self, pos: Pos) -> Option<&mut T> {
+        if self.contains(pos) {
+            Some(&mut self.cells[pos.0 * self.cols + pos.1])
+        } else {
+            None
+        }
+    }
+
+    /// `pos` moved by `d` (a direction or `(dr, dc)`), if that is still on
+    /// the grid.
+    pub fn offset(&self, (r, c): Pos, d: impl Into<Vec2>) -> Option<Pos> {
+        let d = d.into();
+        let pos = (r.checked_add_signed(d.r)?, c.checked_add_signed(d.c)?);
+        self.contains(pos).then_some(pos)
+    }
+
+    /// Position on the grid tiled infinitely in every direction.
+    pub fn wrap(&self, (r, c): (isize, isize)) -> Pos {
+        (
+            r.rem_euclid(self.rows as isize) as usize,
+            c.rem_euclid(self.cols as isize) as usize,
+        )
+    }
+
+    pub fn get_wrapping(&self, pos: (isize, isize)) -> &T {
+        &self[self.wrap(pos)]
+    }
+
+    /// Up, right, down and left of `pos`, where on the grid.
+    pub fn neighbors4(&self, pos: Pos) -> impl Iterator<Item = Pos> + '_ {
+        Dir4::ALL
+            .into_iter()
+            .filter_map(move |d| self.offset(pos, d))
+    }
+
+    /// All eight surrounding positions on the grid, clockwise from above.
+    pub fn neighbors8(&self, pos: Pos) -> impl Iterator<Item = Pos> + '_ {
+        Dir8::ALL
+            .into_iter()
+            .filter_map(move |d| self.offset(pos, d))
+    }
+
+    pub fn positions(&self) -> impl Iterator<Item = Pos> {
+        let cols = self.cols;
+        (0..self.rows).flat_map(move |r| (0..cols).map(move |c| (r, c)))
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (Pos, &T)> {
+        self.positions().zip(&self.cells)
+    }
+
+    pub fn row(&self, r: usize) -> &[T] {
+        &self.cells[r * self.cols..(r + 1) * self.cols]
+    }
+
+    pub fn iter_rows(&self) -> impl Iterator<Item = &[T]> {
+        // `max(1)` keeps `chunks` happy on a grid with no columns
+        self.cells.chunks(self.cols.max(1))
+    }
+
+    pub fn column(&self, c: usize) -> impl Iterator<Item = &T> {
+        assert!(
+            c < self.cols,
+            "column {c} outside {}x{} grid",
+            self.rows,
+            self.cols
+        );
+        self.cells[c..].iter().step_by(self.cols)
+    }
+
+    /// First position holding `value`, in row-major order.
+    pub fn find(&self, value: &T) -> Option<Pos>
+    where
+        T: PartialEq,
+    {
+        self.iter().find(|(_, v)| *v == value).map(|(pos, _)| pos)
+    }
+
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            rows: self.rows,
+            cols: self.cols,
+            cells: self.cells.iter().map(f).collect(),
+        }
+    }
+
+    /// Grid of the given shape whose cell at `pos` is `self[from(pos)]`.
+    fn remap(&self, rows: usize, cols: usize, from: impl Fn(Pos) -> Pos) -> Self
+    where
+        T: Clone,
+    {
+        let cells = (0..rows)
+            .flat_map(|r| (0..cols).map(move |c| (r, c)))
+            .map(|pos| self[from(pos)].clone())
+            .collect();
+        Self { rows, cols, cells }
+    }
+
+    pub fn transpose(&self) -> Self
+    where
+        T: Clone,
+    {
+        self.remap(self.cols, self.rows, |(r, c)| (c, r))
+    }
+
+    pub fn rotate_clockwise(&self) -> Self
+    where
+        T: Clone,
+    {
+        let rows = self.rows;
+        self.remap(self.cols, self.rows, |(r, c)| (rows - 1 - c, r))
+    }
+
+    pub fn rotate_counterclockwise(&self) -> Self
+    where
+        T: Clone,
+    {
+        let cols = self.cols;
+        self.remap(self.cols, self.rows, |(r, c)| (c, cols - 1 - r))
+    }
+
+    /// mirrors left to right
+    pub fn flip_horizontal(&self) -> Self
+    where
+        T: Clone,
+    {
+        let cols = self.cols;
+        self.remap(self.rows, self.cols, |(r, c)| (r, cols - 1 - c))
+    }
+
+    /// mirrors top to bottom
+    pub fn flip_vertical(&self) -> Self
+    where
+        T: Clone,
+    {
+        let rows = self.rows;
+        self.remap(self.rows, self.cols, |(r, c)| (rows - 1 - r, c))
+    }
+}
+
+impl<T> Index<Pos> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, pos: Pos) -> &T {
+        self.get(pos)
+            .unwrap_or_else(|| panic!("{pos:?} outside {}x{} grid", self.rows, self.cols))
+    }
+}
+
+impl<T> IndexMut<Pos> for Grid<T> {
+    fn index_mut(&mut self, pos: Pos) -> &mut T {
+        let (rows, cols) = (self.rows, self.cols);
+        self.get_mut(pos)
+            .unwrap_or_else(|| panic!("{pos:?} outside {rows}x{cols} grid"))
+    }
+}
+
+/// one line per row, cells written back to back
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (r, row) in self.iter_rows().enumerate() {
+            if r > 0 {
+                writeln!(f)?;
+            }
+            for cell in row {
+                write!(f, "{cell}")?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[test]
+fn neighbours() {
+    let grid = Grid::from_rows(vec![vec![1, 2, 3], vec![4, 5, 6]]).unwrap();
+
+    assert_eq!(
+        grid.neighbors4((0, 0)).collect::<Vec<_>>(),
+        [(0, 1), (1, 0)]
+    );
+    assert_eq!(grid.neighbors8((1, 1)).count(), 5);
+    assert_eq!(grid.offset((1, 2), (0, 1)), None);
+    assert_eq!(grid.offset((1, 2), (-1, -2)), Some((0, 0)));
+    assert_eq!(*grid.get_wrapping((-1, 5)), 6);
+    assert_eq!(grid.find(&5), Some((1, 1)));
+    assert_eq!(grid.column(1).copied().collect::<Vec<_>>(), [2, 5]);
+    assert!(std::panic::catch_unwind(|| grid.column(3).count()).is_err());
+    assert!(Grid::from_rows(vec![vec![1], vec![]]).is_none());
+}
+
+#[test]
+fn transforms() {
+    let grid = Grid::from_rows(vec![vec!['a', 'b', 'c'], vec!['d', 'e', 'f']]).unwrap();
+
+    assert_eq!(grid.to_string(), "abc\ndef");
+    assert_eq!(grid.transpose().to_string(), "ad\nbe\ncf");
+    assert_eq!(grid.rotate_clockwise().to_string(), "da\neb\nfc");
+    assert_eq!(grid.rotate_counterclockwise().to_string(), "cf\nbe\nad");
+    assert_eq!(grid.flip_horizontal().to_string(), "cba\nfed");
+    assert_eq!(grid.flip_vertical().to_string(), "def\nabc");
+    assert_eq!(
+        grid.rotate_clockwise().rotate_counterclockwise(),
+        grid.clone()
+    );
+}
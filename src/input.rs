@@ -0,0 +1,127 @@
+//! Puzzle inputs are private and not committed, so they are read at runtime
+//! from (in order of preference) an explicit path, `$AOC_INPUT_DIR`, or the
+//! `inputs/` directory. A path of `-` reads from stdin. Years after 2023
+//! keep theirs in a subdirectory named after the year.
+
+use std::env;
+use std::fs;
+use std::io::{self, BufRead, BufReader, Read};
+use std::path::{Path, PathBuf};
+
+use crate::years;
+
+pub const DIR_VAR: &str = "AOC_INPUT_DIR";
+
+/// Where to look for a day's input.
+#[derive(Debug, Clone, Default)]
+pub enum Source {
+    /// `$AOC_INPUT_DIR`, falling back to `./inputs`
+    #[default]
+    Default,
+    /// a single file, or a directory laid out like `inputs/`
+    Path(PathBuf),
+    Stdin,
+}
+
+impl Source {
+    pub fn from_arg(arg: &str) -> Self {
+        match arg {
+            "-" => Self::Stdin,
+            _ => Self::Path(PathBuf::from(arg)),
+        }
+    }
+}
+
+/// `dayNN.txt`, or `YYYY/dayNN.txt` outside the default year.
+pub fn file_name(year: u32, day: u32) -> String {
+    if year == years::DEFAULT {
+        format!("day{day:02}.txt")
+    } else {
+        format!("{year}/day{day:02}.txt")
+    }
+}
+
+fn default_dir() -> PathBuf {
+    env::var_os(DIR_VAR).map_or_else(|| PathBuf::from("inputs"), PathBuf::from)
+}
+
+pub fn path_for(year: u32, day: u32, source: &Source) -> Option<PathBuf> {
+    match source {
+        Source::Default => Some(default_dir().join(file_name(year, day))),
+        Source::Path(p) if p.is_dir() => Some(p.join(file_name(year, day))),
+        Source::Path(p) => Some(p.clone()),
+        Source::Stdin => None,
+    }
+}
+
+pub fn load(year: u32, day: u32, source: &Source) -> io::Result<String> {
+    match path_for(year, day, source) {
+        Some(path) => read_file(&path),
+        None => {
+            let mut buf = String::new();
+            io::stdin().read_to_string(&mut buf)?;
+            Ok(buf)
+        }
+    }
+}
+
+/// The input as a reader, for days that solve it a line at a time.
+pub fn open(year: u32, day: u32, source: &Source) -> io::Result<Box<dyn BufRead>> {
+    match path_for(year, day, source) {
+        Some(path) => fs::File::open(&path)
+            .map(|file| Box::new(BufReader::new(file)) as Box<dyn BufRead>)
+            .map_err(|e| io::Error::new(e.kind(), format!("{}: {e}", path.display()))),
+        None => Ok(Box::new(io::stdin().lock())),
+    }
+}
+
+/// FNV-1a of the input, ignoring `\r` and trailing whitespace so the same
+/// puzzle saved on different systems hashes the same.
+pub fn hash(input: &str) -> String {
+    let hash = input
+        .trim_end()
+        .bytes()
+        .filter(|&b| b != b'\r')
+        .fold(0xcbf29ce484222325_u64, |h, b| {
+            (h ^ b as u64).wrapping_mul(0x100000001b3)
+        });
+    format!("{hash:016x}")
+}
+
+fn read_file(path: &Path) -> io::Result<String> {
+    fs::read_to_string(path)
+        .map_err(|e| io::Error::new(e.kind(), format!("{}: {e}", path.display())))
+}
+
+/// Input for the `answer()` tests. Returns `None` (and says so) when the
+/// file is absent so a fresh checkout can still run the example tests.
+#[cfg(test)]
+pub fn for_test(year: u32, day: u32) -> Option<String> {
+    use std::io::Write;
+
+    let dir = env::var_os(DIR_VAR).map_or_else(
+        || Path::new(env!("CARGO_MANIFEST_DIR")).join("inputs"),
+        PathBuf::from,
+    );
+    let path = dir.join(file_name(year, day));
+
+    match fs::read_to_string(&path) {
+        Ok(input) => Some(input),
+        Err(_) => {
+            // written to the raw handle so the message survives test output capture
+            let _ = writeln!(
+                io::stderr(),
+                "skipping {year} day {day} answer test: {} not found (set {DIR_VAR})",
+                path.display()
+            );
+            None
+        }
+    }
+}
+
+#[test]
+fn hash_ignores_line_endings() {
+    assert_eq!(hash(""), "cbf29ce484222325");
+    assert_eq!(hash("a\r\nb\r\n"), hash("a\nb"));
+    assert_ne!(hash("a\nb"), hash("b\na"));
+}
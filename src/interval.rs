@@ -0,0 +1,344 @@
+//! Half-open integer ranges and sets of them, for puzzles that push whole
+//! ranges of values through a transformation instead of one value at a
+//! time.
+
+use std::ops::{Add, Mul, Sub};
+
+/// Interval endpoints: integers, with `Default` as zero.
+pub trait Endpoint: Copy + Ord + Default + Add<Output = Self> + Sub<Output = Self> {}
+
+impl<T: Copy + Ord + Default + Add<Output = T> + Sub<Output = T>> Endpoint for T {}
+
+/// `start..end`, empty when `start >= end`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Interval<T> {
+    pub start: T,
+    pub end: T,
+}
+
+impl<T: Endpoint> Interval<T> {
+    pub fn new(start: T, end: T) -> Self {
+        Self { start, end }
+    }
+
+    pub fn from_len(start: T, len: T) -> Self {
+        Self::new(start, start + len)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.start >= self.end
+    }
+
+    pub fn len(&self) -> T {
+        if self.is_empty() {
+            T::default()
+        } else {
+            self.end - self.start
+        }
+    }
+
+    pub fn contains(&self, x: T) -> bool {
+        self.start <= x && x < self.end
+    }
+
+    /// `None` if they do not overlap.
+    pub fn intersection(&self, other: &Self) -> Option<Self> {
+        let i = Self::new(self.start.max(other.start), self.end.min(other.end));
+        (!i.is_empty()).then_some(i)
+    }
+
+    /// The parts below `x` and from `x` on, where non-empty.
+    pub fn split_at(&self, x: T) -> (Option<Self>, Option<Self>) {
+        let below = Self::new(self.start, self.end.min(x));
+        let above = Self::new(self.start.max(x), self.end);
+        (
+            (!below.is_empty()).then_some(below),
+            (!above.is_empty()).then_some(above),
+        )
+    }
+}
+
+/// Disjoint intervals kept sorted, with touching ones merged.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default)]
+pub struct IntervalSet<T> {
+    intervals: Vec<Interval<T>>,
+}
+
+impl<T: Endpoint> IntervalSet<T> {
+    pub fn new() -> Self {
+        Self {
+            intervals: Vec::new(),
+        }
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &Interval<T>> {
+        self.intervals.iter()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.intervals.is_empty()
+    }
+
+    /// number of values covered
+    pub fn len(&self) -> T {
+        self.intervals
+            .iter()
+            .fold(T::default(), |acc, i| acc + i.len())
+    }
+
+    pub fn min(&self) -> Option<T> {
+        self.intervals.first().map(|i| i.start)
+    }
+
+    pub fn contains(&self, x: T) -> bool {
+        let i = self.intervals.partition_point(|i| i.end <= x);
+        self.intervals.get(i).is_some_and(|i| i.contains(x))
+    }
+
+    pub fn insert(&mut self, interval: Interval<T>) {
+        if interval.is_empty() {
+            return;
+        }
+        // everything overlapping or touching `interval` is folded into it
+        let lo = self.intervals.partition_point(|i| i.end < interval.start);
+        let hi = self.intervals.partition_point(|i| i.start <= interval.end);
+        let merged = self.intervals[lo..hi].iter().fold(interval, |acc, i| {
+            Interval::new(acc.start.min(i.start), acc.end.max(i.end))
+        });
+        self.intervals.splice(lo..hi, [merged]);
+    }
+
+    pub fn union(&self, other: &Self) -> Self {
+        let mut set = self.clone();
+        for &i in &other.intervals {
+            set.insert(i);
+        }
+        set
+    }
+
+    pub fn intersection(&self, other: &Self) -> Self {
+        let (mut a, mut b) = (self.intervals.iter(), other.intervals.iter());
+        let (mut x, mut y) = (a.next(), b.next());
+        let mut intervals = Vec::new();
+        while let (Some(i), Some(j)) = (x, y) {
+            intervals.extend(i.intersection(j));
+            if i.end < j.end {
+                x = a.next();
+            } else {
+                y = b.next();
+            }
+        }
+        Self { intervals }
+    }
+
+    pub fn difference(&self, other: &Self) -> Self {
+        let mut intervals = Vec::new();
+        for &i in &self.intervals {
+            let mut rest = Some(i);
+            for j in &other.intervals {
+                let Some(r) = rest else { break };
+                if j.end <= r.start {
+                    continue;
+                }
+                if j.start >= r.end {
+                    break;
+                }
+                let (below, _) = r.split_at(j.start);
+                intervals.extend(below);
+                rest = r.split_at(j.end).1;
+            }
+            intervals.extend(rest);
+        }
+        Self { intervals }
+    }
+
+    /// The values below `x` and those from `x` on.
+    pub fn split_at(&self, x: T) -> (Self, Self) {
+        let (mut below, mut above) = (Vec::new(), Vec::new());
+        for i in &self.intervals {
+            let (b, a) = i.split_at(x);
+            below.extend(b);
+            above.extend(a);
+        }
+        (Self { intervals: below }, Self { intervals: above })
+    }
+}
+
+impl<T: Endpoint> FromIterator<Interval<T>> for IntervalSet<T> {
+    fn from_iter<I: IntoIterator<Item = Interval<T>>>(iter: I) -> Self {
+        let mut set = Self::new();
+        for i in iter {
+            set.insert(i);
+        }
+        set
+    }
+}
+
+/// Piecewise translation: values in each source interval move by the same
+/// amount, anything outside every piece maps to itself.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct OffsetMap<T> {
+    /// `(source, destination start)`, sorted and non-overlapping
+    pieces: Vec<(Interval<T>, T)>,
+}
+
+impl<T: Endpoint> OffsetMap<T> {
+    /// `None` if two source intervals overlap.
+    pub fn new(mut pieces: Vec<(Interval<T>, T)>) -> Option<Self> {
+        pieces.retain(|(src, _)| !src.is_empty());
+        pieces.sort();
+        if pieces.windows(2).any(|w| w[0].0.end > w[1].0.start) {
+            return None;
+        }
+        Some(Self { pieces })
+    }
+
+    pub fn get(&self, x: T) -> T {
+        match self.pieces.iter().find(|(src, _)| src.contains(x)) {
+            Some(&(src, dst)) => x - src.start + dst,
+            None => x,
+        }
+    }
+
+    pub fn apply(&self, set: &IntervalSet<T>) -> IntervalSet<T> {
+        let sources: IntervalSet<T> = self.pieces.iter().map(|&(src, _)| src).collect();
+        let mut out = set.difference(&sources);
+
+        for &(src, dst) in &self.pieces {
+            for i in set.iter().filter_map(|i| i.intersection(&src)) {
+                out.insert(Interval::new(
+                    i.start - src.start + dst,
+                    i.end - src.start + dst,
+                ));
+            }
+        }
+
+        out
+    }
+}
+
+/// Axis-aligned box in `N` dimensions, one interval per axis.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Cuboid<T, const N: usize>(pub [Interval<T>; N]);
+
+/// The `xmas` ratings of day 19.
+pub type Box4<T> = Cuboid<T, 4>;
+
+impl<T: Endpoint, const N: usize> Cuboid<T, N> {
+    pub fn is_empty(&self) -> bool {
+        self.0.iter().any(Interval::is_empty)
+    }
+
+    /// number of points inside
+    pub fn volume(&self) -> T
+    where
+        T: Mul<Output = T>,
+    {
+        self.0
+            .iter()
+            .map(Interval::len)
+            .reduce(|a, b| a * b)
+            .unwrap_or_default()
+    }
+
+    pub fn contains(&self, point: [T; N]) -> bool {
+        self.0.iter().zip(point).all(|(i, x)| i.contains(x))
+    }
+
+    pub fn intersection(&self, other: &Self) -> Option<Self> {
+        let mut axes = self.0;
+        for (axis, o) in axes.iter_mut().zip(&other.0) {
+            *axis = axis.intersection(o)?;
+        }
+        Some(Self(axes))
+    }
+
+    /// The parts below `x` and from `x` on along `axis`, where non-empty.
+    pub fn split_at(&self, axis: usize, x: T) -> (Option<Self>, Option<Self>) {
+        let (below, above) = self.0[axis].split_at(x);
+        let with = |i| {
+            let mut axes = self.0;
+            axes[axis] = i;
+            Self(axes)
+        };
+        (below.map(with), above.map(with))
+    }
+}
+
+/// small deterministic generator so the brute-force checks need no crates
+#[cfg(test)]
+fn lcg(seed: &mut u64) -> u64 {
+    *seed = seed
+        .wrapping_mul(6364136223846793005)
+        .wrapping_add(1442695040888963407);
+    *seed >> 33
+}
+
+#[test]
+fn set_ops_match_brute_force() {
+    use std::collections::BTreeSet;
+
+    let points = |set: &IntervalSet<i64>| -> BTreeSet<i64> {
+        set.iter().flat_map(|i| i.start..i.end).collect()
+    };
+
+    let mut seed = 5;
+    let random_set = |seed: &mut u64| {
+        (0..lcg(seed) % 6)
+            .map(|_| Interval::from_len(lcg(seed) as i64 % 60 - 10, lcg(seed) as i64 % 12))
+            .collect::<IntervalSet<i64>>()
+    };
+
+    for _ in 0..500 {
+        let (a, b) = (random_set(&mut seed), random_set(&mut seed));
+        let (pa, pb) = (points(&a), points(&b));
+
+        // normalised: sorted, non-empty and not touching
+        assert!(a.intervals.windows(2).all(|w| w[0].end < w[1].start));
+        assert!(a.iter().all(|i| !i.is_empty()));
+
+        assert_eq!(points(&a.union(&b)), &pa | &pb);
+        assert_eq!(points(&a.intersection(&b)), &pa & &pb);
+        assert_eq!(points(&a.difference(&b)), &pa - &pb);
+        assert_eq!(a.len() as usize, pa.len());
+        assert_eq!(a.min(), pa.first().copied());
+
+        let x = lcg(&mut seed) as i64 % 60 - 10;
+        let (below, above) = a.split_at(x);
+        assert_eq!(
+            points(&below),
+            pa.iter().copied().filter(|&p| p < x).collect()
+        );
+        assert_eq!(
+            points(&above),
+            pa.iter().copied().filter(|&p| p >= x).collect()
+        );
+        assert_eq!(a.contains(x), pa.contains(&x));
+
+        let map = OffsetMap::new(vec![
+            (Interval::new(0, 10), 100),
+            (Interval::new(20, 25), -5),
+        ])
+        .unwrap();
+        let mapped: BTreeSet<i64> = pa.iter().map(|&p| map.get(p)).collect();
+        assert_eq!(points(&map.apply(&a)), mapped);
+    }
+
+    assert!(OffsetMap::new(vec![(Interval::new(0, 10), 0), (Interval::new(9, 12), 0)]).is_none());
+}
+
+#[test]
+fn cuboids() {
+    let cube: Box4<usize> = Cuboid([Interval::new(1, 4001); 4]);
+    assert_eq!(cube.volume(), 4000_usize.pow(4));
+
+    let (below, above) = cube.split_at(2, 1000);
+    assert_eq!(
+        below.unwrap().volume() + above.unwrap().volume(),
+        cube.volume()
+    );
+    assert!(below.unwrap().contains([1, 1, 999, 4000]));
+    assert!(!below.unwrap().contains([1, 1, 1000, 4000]));
+    assert_eq!(cube.split_at(0, 1).0, None);
+    assert_eq!(below.unwrap().intersection(&above.unwrap()), None);
+}
@@ -0,0 +1,30 @@
+//! Advent of Code 2023 solutions plus the shared pieces the `aoc` runner and
+//! other tooling build on.
+
+pub mod answers;
+pub mod bench;
+pub mod cycle;
+pub mod days;
+pub mod fixture;
+pub mod gen;
+pub mod geom;
+pub mod graph;
+pub mod grid;
+pub mod input;
+pub mod interval;
+pub mod math;
+pub mod par;
+pub mod params;
+pub mod parse;
+pub mod prop;
+pub mod render;
+pub mod report;
+pub mod scaffold;
+mod solution;
+pub mod stats;
+pub mod stream;
+pub mod visual;
+pub mod years;
+
+pub use parse::ParseError;
+pub use solution::{Assumption, DynSolution, Run, Solution, Timed};
@@ -1,3 +1,1133 @@
-fn main() {
-    println!("Hello, world!");
+use std::fs;
+use std::io::Read;
+use std::ops::RangeInclusive;
+use std::path::Path;
+use std::process::ExitCode;
+use std::thread;
+use std::time::Duration;
+
+use aoc_2023::answers::{self, Entry, Key, Registry, Verdict};
+use aoc_2023::bench::{self, BenchError};
+use aoc_2023::fixture::Fixture;
+use aoc_2023::gen::{self, Rng};
+use aoc_2023::input::{self, Source};
+use aoc_2023::par;
+use aoc_2023::params::{Param, Params};
+use aoc_2023::render::{Format, RenderError, Renderer};
+use aoc_2023::report::{self, Record};
+use aoc_2023::scaffold;
+use aoc_2023::stats::{self, Stats};
+use aoc_2023::visual::Visualizer;
+use aoc_2023::years;
+use aoc_2023::{DynSolution, Run};
+
+const USAGE: &str = "Usage: aoc run <days> [-] [--part <1|2>] [--input <file|dir|->]
+                [--year YEAR] [--visualize] [--delay MS] [--threads N]
+                [--concurrent] [--stream] [--example NAME]
+                [--param NAME=VALUE]... [--format table|json|csv] [--stats]
+       aoc bench <days> [--runs N] [--input <file|dir>] [--year YEAR]
+                 [--out FILE] [--baseline FILE] [--threshold PCT]
+                 [--threads N]
+       aoc verify [days] [--input <file|dir>] [--year YEAR]
+                  [--answers FILE] [--profile NAME] [--record]
+       aoc check [days] [--input <file|dir|->] [--year YEAR]
+       aoc render <days> [--input <file|dir|->] [--year YEAR] [--out DIR]
+                  [--format png|ppm|svg] [--scale N]
+       aoc gen <day> [--seed N] [--size N]
+       aoc new <year> <day>
+       aoc params [days] [--year YEAR]
+
+<days>       all | 17 | 5..12 | 1,3,5..8   (ranges are inclusive)
+--input      puzzle input file, directory of dayNN.txt files, or - for stdin
+             (default: $AOC_INPUT_DIR, then ./inputs); years other than
+             2023 keep theirs in a YEAR/ subdirectory
+--year       which event's days to work on (default: 2023)
+--visualize  play the simulation of each day that has one (14, 16, 21, 22) in
+             the terminal before solving; space pauses, s steps, +/- change
+             the speed, q skips to the next day
+--delay      milliseconds between frames (default: 50)
+--threads    threads for the days that split their work up (12, 16, 22, 25);
+             answers are the same on any count (default: 1)
+--concurrent run the days side by side, each on its own thread
+--stream     read the input a line at a time for the days that can (1, 2, 4,
+             7, 9, 12), as they always do from stdin
+--example    run examples/DD/NAME.txt instead of the input: the parts it
+             has answers for, with its parameters, checking the answers
+--param      change one of a puzzle's numbers, like `--param steps=5000` for
+             day 21; repeat for more, and see `aoc params` for what each day
+             takes (an example's own answers are not checked with it)
+--stats      show what each part counted, like heap pushes in days 17 and
+             23 or memo hits in day 12; needs a build with `--features
+             stats`, as the counters are compiled out otherwise
+--runs       timed runs per day (default: 5)
+--out        where to write the JSON results (default: bench_output.txt), or
+             for render the directory for the pictures (default: renders)
+--baseline   results of an earlier bench to compare against
+--threshold  slowdown of the median, in percent, flagged as a regression
+             (default: 10)
+--answers    known answers (default: answers.txt, or answers/YEAR.txt for
+             other years)
+--profile    answers to fall back on when none match the input's hash
+             (default: default)
+--record     add answers not known yet, keyed by input hash (and --profile
+             if given)
+--format     for run, how to print the results: a table, or a JSON or CSV
+             record per part (default: table); for render, the picture file
+             format (default: png)
+--scale      pixels per grid cell (default: 4)
+--seed       seed for the random input (default: 0)
+--size       how big a random input to make (default: 10)
+
+check tests the properties of the input that solutions rely on beyond the
+puzzle text (days without any are skipped). render draws grid days (10, 14,
+16, 17, 23) and skips the rest. gen prints a random input for any day.
+new, run from the crate root, starts a day of another year: a solution
+module in src/yYEAR/ registered with the runner, an empty
+examples/YEAR/DD/example.txt and answers/YEAR.txt. params lists the
+numbers each day takes from the puzzle text, with the part each uses them
+in and their defaults.";
+
+fn main() -> ExitCode {
+    let args = std::env::args().skip(1).collect::<Vec<_>>();
+
+    match args.first().map(String::as_str) {
+        Some("run") => match parse_run_args(&args[1..]) {
+            Ok(opts) => run(&opts),
+            Err(msg) => usage_error(&msg),
+        },
+        Some("bench") => match parse_bench_args(&args[1..]) {
+            Ok(opts) => run_bench(&opts),
+            Err(msg) => usage_error(&msg),
+        },
+        Some("verify") => match parse_verify_args(&args[1..]) {
+            Ok(opts) => verify(&opts),
+            Err(msg) => usage_error(&msg),
+        },
+        Some("check") => match parse_check_args(&args[1..]) {
+            Ok(opts) => check(&opts),
+            Err(msg) => usage_error(&msg),
+        },
+        Some("render") => match parse_render_args(&args[1..]) {
+            Ok(opts) => render(&opts),
+            Err(msg) => usage_error(&msg),
+        },
+        Some("gen") => match parse_gen_args(&args[1..]) {
+            Ok(opts) => generate(&opts),
+            Err(msg) => usage_error(&msg),
+        },
+        Some("new") => match parse_new_args(&args[1..]) {
+            Ok((year, day)) => new_day(year, day),
+            Err(msg) => usage_error(&msg),
+        },
+        Some("params") => match parse_params_args(&args[1..]) {
+            Ok((year, days)) => list_params(year, &days),
+            Err(msg) => usage_error(&msg),
+        },
+        Some("-h" | "--help") => {
+            println!("{USAGE}");
+            ExitCode::SUCCESS
+        }
+        Some(cmd) => usage_error(&format!("unknown command `{cmd}`")),
+        None => usage_error("missing command"),
+    }
+}
+
+fn usage_error(msg: &str) -> ExitCode {
+    eprintln!("error: {msg}\n\n{USAGE}");
+    ExitCode::from(2)
+}
+
+struct RunOpts {
+    year: u32,
+    days: Vec<u32>,
+    parts: Vec<u32>,
+    source: Source,
+    visualize: bool,
+    delay: Duration,
+    concurrent: bool,
+    stream: bool,
+    example: Option<String>,
+    format: report::Format,
+    params: Params,
+    stats: bool,
+}
+
+fn parse_run_args(args: &[String]) -> Result<RunOpts, String> {
+    let mut year = years::DEFAULT;
+    let mut days = None;
+    let mut parts = vec![1, 2];
+    let mut source = Source::Default;
+    let mut visualize = false;
+    let mut delay = Duration::from_millis(50);
+    let mut concurrent = false;
+    let mut stream = false;
+    let mut example = None;
+    let mut format = report::Format::Table;
+    let mut show_stats = false;
+    let mut params = Params::default();
+
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--part" | "-p" => {
+                let part = args.next().ok_or("--part needs a value")?;
+                parts = match part.as_str() {
+                    "1" => vec![1],
+                    "2" => vec![2],
+                    _ => return Err(format!("invalid part `{part}`")),
+                };
+            }
+            "--input" | "-i" => {
+                source = Source::from_arg(args.next().ok_or("--input needs a value")?);
+            }
+            "--year" | "-y" => year = parse_year(args.next())?,
+            "--visualize" | "-v" => visualize = true,
+            "--delay" | "-d" => {
+                let ms = args.next().ok_or("--delay needs a value")?;
+                let ms = ms.parse().map_err(|_| format!("invalid delay `{ms}`"))?;
+                delay = Duration::from_millis(ms);
+            }
+            "--threads" | "-j" => {
+                par::set_threads(parse_threads(args.next())?);
+            }
+            "--concurrent" => concurrent = true,
+            "--stream" => stream = true,
+            "--stats" if !stats::ENABLED => {
+                return Err("--stats needs a build with `--features stats`".to_string());
+            }
+            "--stats" => show_stats = true,
+            "--format" | "-f" => {
+                let name = args.next().ok_or("--format needs a value")?;
+                format =
+                    report::Format::from_name(name).ok_or(format!("invalid format `{name}`"))?;
+            }
+            "--example" | "-e" => {
+                example = Some(args.next().ok_or("--example needs a value")?.clone());
+            }
+            "--param" | "-P" => {
+                let param = args.next().ok_or("--param needs a value")?;
+                match param.split_once('=') {
+                    Some((name, value)) if !name.is_empty() && !value.is_empty() => {
+                        params.set(name, value);
+                    }
+                    _ => return Err(format!("invalid parameter `{param}`; expected NAME=VALUE")),
+                }
+            }
+            // `aoc run 4 -` pipes the input in
+            "-" if days.is_some() => source = Source::Stdin,
+            _ if days.is_none() => days = Some(parse_days(arg)?),
+            _ => return Err(format!("unexpected argument `{arg}`")),
+        }
+    }
+
+    let days = days.ok_or("missing <days>")?;
+    if days.len() > 1 && matches!(source, Source::Stdin) {
+        return Err("stdin input only works with a single day".to_string());
+    }
+    if example.is_some() && !matches!(source, Source::Default) {
+        return Err("--example brings its own input".to_string());
+    }
+    let declared = days
+        .iter()
+        .filter_map(|&d| years::get(year, d))
+        .flat_map(|solution| solution.params())
+        .copied()
+        .collect::<Vec<_>>();
+    if let Some(name) = params.unknown(&declared).next() {
+        return Err(format!(
+            "none of the days takes a parameter `{name}`; see `aoc params`"
+        ));
+    }
+
+    Ok(RunOpts {
+        year,
+        days,
+        parts,
+        source,
+        visualize,
+        delay,
+        concurrent,
+        stream,
+        example,
+        format,
+        params,
+        stats: show_stats,
+    })
+}
+
+struct BenchOpts {
+    year: u32,
+    days: Vec<u32>,
+    source: Source,
+    runs: usize,
+    out: String,
+    baseline: Option<String>,
+    threshold: f64,
+}
+
+fn parse_bench_args(args: &[String]) -> Result<BenchOpts, String> {
+    let mut days = None;
+    let mut opts = BenchOpts {
+        year: years::DEFAULT,
+        days: Vec::new(),
+        source: Source::Default,
+        runs: 5,
+        out: "bench_output.txt".to_string(),
+        baseline: None,
+        threshold: 10.0,
+    };
+
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        let mut value = |name: &str| args.next().ok_or(format!("{name} needs a value"));
+        match arg.as_str() {
+            "--runs" | "-n" => {
+                let runs = value("--runs")?;
+                opts.runs = match runs.parse() {
+                    Ok(n) if n > 0 => n,
+                    _ => return Err(format!("invalid run count `{runs}`")),
+                };
+            }
+            "--input" | "-i" => opts.source = Source::from_arg(value("--input")?),
+            "--year" | "-y" => opts.year = parse_year(args.next())?,
+            "--out" | "-o" => opts.out = value("--out")?.clone(),
+            "--baseline" | "-b" => opts.baseline = Some(value("--baseline")?.clone()),
+            "--threshold" | "-t" => {
+                let pct = value("--threshold")?;
+                opts.threshold = pct
+                    .parse()
+                    .map_err(|_| format!("invalid threshold `{pct}`"))?;
+            }
+            "--threads" | "-j" => par::set_threads(parse_threads(args.next())?),
+            _ if days.is_none() => days = Some(parse_days(arg)?),
+            _ => return Err(format!("unexpected argument `{arg}`")),
+        }
+    }
+
+    opts.days = days.ok_or("missing <days>")?;
+    if matches!(opts.source, Source::Stdin) {
+        return Err("bench reads each input repeatedly, so it needs a file".to_string());
+    }
+
+    Ok(opts)
+}
+
+struct VerifyOpts {
+    year: u32,
+    days: Vec<u32>,
+    source: Source,
+    answers: String,
+    profile: Option<String>,
+    record: bool,
+}
+
+fn parse_verify_args(args: &[String]) -> Result<VerifyOpts, String> {
+    let mut opts = VerifyOpts {
+        year: years::DEFAULT,
+        days: ALL_DAYS.collect(),
+        source: Source::Default,
+        answers: String::new(),
+        profile: None,
+        record: false,
+    };
+    let mut days = None;
+    let mut answers = None;
+
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        let mut value = |name: &str| args.next().ok_or(format!("{name} needs a value"));
+        match arg.as_str() {
+            "--input" | "-i" => opts.source = Source::from_arg(value("--input")?),
+            "--year" | "-y" => opts.year = parse_year(args.next())?,
+            "--answers" | "-a" => answers = Some(value("--answers")?.clone()),
+            "--profile" => opts.profile = Some(value("--profile")?.clone()),
+            "--record" => opts.record = true,
+            _ if days.is_none() => days = Some(parse_days(arg)?),
+            _ => return Err(format!("unexpected argument `{arg}`")),
+        }
+    }
+
+    if let Some(days) = days {
+        opts.days = days;
+    }
+    opts.answers = answers.unwrap_or_else(|| answers::file_for(opts.year));
+    if opts.days.len() > 1 && matches!(opts.source, Source::Stdin) {
+        return Err("stdin input only works with a single day".to_string());
+    }
+
+    Ok(opts)
+}
+
+struct CheckOpts {
+    year: u32,
+    days: Vec<u32>,
+    source: Source,
+}
+
+fn parse_check_args(args: &[String]) -> Result<CheckOpts, String> {
+    let mut year = years::DEFAULT;
+    let mut days = None;
+    let mut source = Source::Default;
+
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--input" | "-i" => {
+                source = Source::from_arg(args.next().ok_or("--input needs a value")?);
+            }
+            "--year" | "-y" => year = parse_year(args.next())?,
+            _ if days.is_none() => days = Some(parse_days(arg)?),
+            _ => return Err(format!("unexpected argument `{arg}`")),
+        }
+    }
+
+    let days = days.unwrap_or_else(|| ALL_DAYS.collect());
+    if days.len() > 1 && matches!(source, Source::Stdin) {
+        return Err("stdin input only works with a single day".to_string());
+    }
+
+    Ok(CheckOpts { year, days, source })
+}
+
+struct RenderOpts {
+    year: u32,
+    days: Vec<u32>,
+    source: Source,
+    out: String,
+    format: Format,
+    scale: usize,
+}
+
+fn parse_render_args(args: &[String]) -> Result<RenderOpts, String> {
+    let mut days = None;
+    let mut opts = RenderOpts {
+        year: years::DEFAULT,
+        days: Vec::new(),
+        source: Source::Default,
+        out: "renders".to_string(),
+        format: Format::Png,
+        scale: 4,
+    };
+
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        let mut value = |name: &str| args.next().ok_or(format!("{name} needs a value"));
+        match arg.as_str() {
+            "--input" | "-i" => opts.source = Source::from_arg(value("--input")?),
+            "--year" | "-y" => opts.year = parse_year(args.next())?,
+            "--out" | "-o" => opts.out = value("--out")?.clone(),
+            "--format" | "-f" => {
+                let name = value("--format")?;
+                opts.format = Format::from_name(name).ok_or(format!("invalid format `{name}`"))?;
+            }
+            "--scale" | "-s" => {
+                let scale = value("--scale")?;
+                opts.scale = match scale.parse() {
+                    Ok(n) if n > 0 => n,
+                    _ => return Err(format!("invalid scale `{scale}`")),
+                };
+            }
+            _ if days.is_none() => days = Some(parse_days(arg)?),
+            _ => return Err(format!("unexpected argument `{arg}`")),
+        }
+    }
+
+    opts.days = days.ok_or("missing <days>")?;
+    if opts.days.len() > 1 && matches!(opts.source, Source::Stdin) {
+        return Err("stdin input only works with a single day".to_string());
+    }
+
+    Ok(opts)
+}
+
+struct GenOpts {
+    day: u32,
+    seed: u64,
+    size: usize,
+}
+
+fn parse_gen_args(args: &[String]) -> Result<GenOpts, String> {
+    let mut day = None;
+    let mut opts = GenOpts {
+        day: 0,
+        seed: 0,
+        size: 10,
+    };
+
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        let mut value = |name: &str| args.next().ok_or(format!("{name} needs a value"));
+        match arg.as_str() {
+            "--seed" => {
+                let seed = value("--seed")?;
+                opts.seed = seed.parse().map_err(|_| format!("invalid seed `{seed}`"))?;
+            }
+            "--size" => {
+                let size = value("--size")?;
+                opts.size = size.parse().map_err(|_| format!("invalid size `{size}`"))?;
+            }
+            _ if day.is_none() => match parse_days(arg)?[..] {
+                [d] => day = Some(d),
+                _ => return Err("gen makes one day at a time".to_string()),
+            },
+            _ => return Err(format!("unexpected argument `{arg}`")),
+        }
+    }
+
+    opts.day = day.ok_or("missing <day>")?;
+    Ok(opts)
+}
+
+fn parse_new_args(args: &[String]) -> Result<(u32, u32), String> {
+    let [year, day] = args else {
+        return Err("new takes a <year> and a <day>".to_string());
+    };
+    let year = year.parse().map_err(|_| format!("invalid year `{year}`"))?;
+    match day.parse() {
+        Ok(day) if ALL_DAYS.contains(&day) => Ok((year, day)),
+        _ => Err(format!("invalid day `{day}`")),
+    }
+}
+
+fn parse_params_args(args: &[String]) -> Result<(u32, Vec<u32>), String> {
+    let mut year = years::DEFAULT;
+    let mut days = None;
+
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--year" | "-y" => year = parse_year(args.next())?,
+            _ if days.is_none() => days = Some(parse_days(arg)?),
+            _ => return Err(format!("unexpected argument `{arg}`")),
+        }
+    }
+
+    Ok((year, days.unwrap_or_else(|| ALL_DAYS.collect())))
+}
+
+fn parse_year(arg: Option<&String>) -> Result<u32, String> {
+    let year = arg.ok_or("--year needs a value")?;
+    match year.parse() {
+        Ok(y) if years::days(y).is_some() => Ok(y),
+        Ok(y) => Err(format!(
+            "no days for {y} yet; add one with `aoc new {y} <day>`"
+        )),
+        Err(_) => Err(format!("invalid year `{year}`")),
+    }
+}
+
+fn parse_threads(arg: Option<&String>) -> Result<usize, String> {
+    let n = arg.ok_or("--threads needs a value")?;
+    match n.parse() {
+        Ok(n) if n > 0 => Ok(n),
+        _ => Err(format!("invalid thread count `{n}`")),
+    }
+}
+
+const ALL_DAYS: RangeInclusive<u32> = 1..=25;
+
+/// Days missing from the year's registry are left out when running.
+fn parse_days(spec: &str) -> Result<Vec<u32>, String> {
+    if spec == "all" {
+        return Ok(ALL_DAYS.collect());
+    }
+
+    let parse_one = |s: &str| match s.trim().parse::<u32>() {
+        Ok(day) if ALL_DAYS.contains(&day) => Ok(day),
+        _ => Err(format!("invalid day `{s}`")),
+    };
+
+    let mut days = Vec::new();
+    for item in spec.split(',') {
+        match item.split_once("..") {
+            Some((lo, hi)) => {
+                let hi = hi.strip_prefix('=').unwrap_or(hi);
+                days.extend(parse_one(lo)?..=parse_one(hi)?);
+            }
+            None => days.push(parse_one(item)?),
+        }
+    }
+    days.sort_unstable();
+    days.dedup();
+
+    Ok(days)
+}
+
+fn run(opts: &RunOpts) -> ExitCode {
+    if opts.visualize {
+        visualize(opts);
+    }
+
+    let solutions = opts.days.iter().filter_map(|&d| years::get(opts.year, d));
+    let stream = opts.stream || matches!(opts.source, Source::Stdin);
+    let solve = |solution| {
+        if let Some(name) = &opts.example {
+            example_day(solution, &opts.parts, name, &opts.params)
+        } else if stream {
+            stream_day(solution, &opts.parts, &opts.source, &opts.params)
+        } else {
+            solve_day(solution, &opts.parts, &opts.source, &opts.params)
+        }
+    };
+    let mut records: Vec<Record> = if opts.concurrent {
+        thread::scope(|scope| {
+            let running = solutions
+                .map(|solution| scope.spawn(move || solve(solution)))
+                .collect::<Vec<_>>();
+            // panics inside a day are already caught and reported by its run
+            running
+                .into_iter()
+                .flat_map(|day| day.join().unwrap())
+                .collect()
+        })
+    } else {
+        solutions.flat_map(solve).collect()
+    };
+
+    if !opts.stats {
+        for r in &mut records {
+            r.stats = Stats::default();
+        }
+    }
+    print!("{}", opts.format.write(&records));
+
+    if records.iter().all(|r| r.answer.is_ok()) {
+        ExitCode::SUCCESS
+    } else {
+        ExitCode::FAILURE
+    }
+}
+
+/// Plays each day's simulation, reporting problems on stderr once the
+/// terminal is back to normal.
+fn visualize(opts: &RunOpts) {
+    let mut problems = Vec::new();
+    let mut visualizer = Visualizer::new(opts.delay);
+
+    for solution in opts.days.iter().filter_map(|&d| years::get(opts.year, d)) {
+        let day = solution.day();
+        // stdin can only be read once, by the run itself
+        if matches!(opts.source, Source::Stdin) {
+            problems.push(format!("day {day}: cannot visualize input from stdin"));
+            continue;
+        }
+        let input = match input::load(solution.year(), day, &opts.source) {
+            Ok(input) => input,
+            Err(e) => {
+                problems.push(format!("day {day}: {e}"));
+                continue;
+            }
+        };
+
+        let before = visualizer.frames();
+        match solution.visualize(&input, &mut visualizer) {
+            Ok(()) if visualizer.frames() == before => {
+                problems.push(format!("day {day}: nothing to visualize"));
+            }
+            Ok(()) => (),
+            Err(RenderError::Parse(e)) => problems.push(format!("{e}\n")),
+            Err(RenderError::Io(e)) => problems.push(format!("day {day}: {e}")),
+        }
+    }
+
+    drop(visualizer);
+    for problem in problems {
+        eprintln!("{problem}");
+    }
+}
+
+/// Runs the requested parts the day has, reporting load and parse problems on
+/// stderr.
+fn solve_day(
+    solution: &dyn DynSolution,
+    parts: &[u32],
+    source: &Source,
+    params: &Params,
+) -> Vec<Record> {
+    let day = solution.day();
+    let parts = requested(solution, parts);
+
+    let input = match input::load(solution.year(), day, source) {
+        Ok(input) => input,
+        Err(e) => {
+            eprintln!("day {day}: {e}");
+            return failed(solution, &parts, params, "no input");
+        }
+    };
+
+    let run = solution.run_with(&input, &parts, params);
+    hashed(records(solution, &parts, params, run), &input)
+}
+
+/// Like `solve_day`, but days that can are fed the input a line at a time
+/// instead of reading it all first, so there is no input hash for them.
+fn stream_day(
+    solution: &dyn DynSolution,
+    parts: &[u32],
+    source: &Source,
+    params: &Params,
+) -> Vec<Record> {
+    let day = solution.day();
+    let parts = requested(solution, parts);
+
+    let read = input::open(solution.year(), day, source).and_then(|mut input| {
+        match solution.stream(&mut input, &parts, params) {
+            Some(run) => Ok(records(solution, &parts, params, run?)),
+            None => {
+                let mut whole = String::new();
+                input.read_to_string(&mut whole)?;
+                let run = solution.run_with(&whole, &parts, params);
+                Ok(hashed(records(solution, &parts, params, run), &whole))
+            }
+        }
+    });
+    read.unwrap_or_else(|e| {
+        eprintln!("day {day}: {e}");
+        failed(solution, &parts, params, "no input")
+    })
+}
+
+/// Runs the day's example fixture `name` with its parameters, on the
+/// requested parts it has answers for (all of them if it has none yet),
+/// reporting wrong answers as failures. Parameters given on the command line
+/// win over the fixture's, and then its answers no longer apply.
+fn example_day(
+    solution: &dyn DynSolution,
+    parts: &[u32],
+    name: &str,
+    params: &Params,
+) -> Vec<Record> {
+    let day = solution.day();
+    let mut parts = requested(solution, parts);
+
+    let fixture = match Fixture::load(Path::new("."), solution.year(), day, name) {
+        Ok(fixture) => fixture,
+        Err(e) => {
+            eprintln!("day {day}: {e}");
+            return failed(solution, &parts, params, "no example");
+        }
+    };
+    if !fixture.expected.is_empty() {
+        parts.retain(|p| fixture.parts().contains(p));
+    }
+
+    let mut given = fixture.params.clone();
+    for (name, value) in params.iter() {
+        given.set(name, value);
+    }
+    let run = solution.run_with(&fixture.input, &parts, &given);
+    let mut records = hashed(records(solution, &parts, &given, run), &fixture.input);
+    if given != fixture.params {
+        return records;
+    }
+    for r in &mut records {
+        let expected = fixture.expected.iter().find(|(part, _)| *part == r.part);
+        if let (Ok(answer), Some((_, expected))) = (&r.answer, expected) {
+            if answer != expected {
+                r.answer = Err(format!("{answer} (expected {expected})"));
+            }
+        }
+    }
+    records
+}
+
+/// `parts` that the day has
+fn requested(solution: &dyn DynSolution, parts: &[u32]) -> Vec<u32> {
+    parts
+        .iter()
+        .copied()
+        .filter(|p| solution.parts().contains(p))
+        .collect()
+}
+
+/// A record for each of `parts` that could not be worked out, for `reason`.
+fn failed(solution: &dyn DynSolution, parts: &[u32], params: &Params, reason: &str) -> Vec<Record> {
+    parts
+        .iter()
+        .map(|&part| {
+            record(
+                solution,
+                part,
+                params,
+                Err(reason.to_string()),
+                Duration::ZERO,
+            )
+        })
+        .collect()
+}
+
+/// The answers of `run`, reporting a parse error on stderr.
+fn records(solution: &dyn DynSolution, parts: &[u32], params: &Params, run: Run) -> Vec<Record> {
+    match run.parse.value {
+        Some(Ok(())) => (),
+        Some(Err(e)) => {
+            eprintln!("{e}\n");
+            return failed(solution, parts, params, "parse error");
+        }
+        None => return failed(solution, parts, params, "parse failed"),
+    }
+
+    run.answers
+        .into_iter()
+        .map(|(part, answer)| {
+            let value = answer.value.ok_or_else(|| "FAILED".to_string());
+            Record {
+                stats: answer.stats,
+                ..record(solution, part, params, value, answer.elapsed)
+            }
+        })
+        .collect()
+}
+
+/// `params` as given, resolved to what `part` ran with.
+fn record(
+    solution: &dyn DynSolution,
+    part: u32,
+    params: &Params,
+    answer: Result<String, String>,
+    elapsed: Duration,
+) -> Record {
+    Record {
+        year: solution.year(),
+        day: solution.day(),
+        part,
+        answer,
+        answer_type: solution.answer_type(part),
+        elapsed,
+        input_hash: None,
+        params: params.resolve(solution.params(), part),
+        stats: Stats::default(),
+    }
+}
+
+/// `records` of a run on `input`, with its hash
+fn hashed(mut records: Vec<Record>, input: &str) -> Vec<Record> {
+    let hash = input::hash(input);
+    for r in &mut records {
+        r.input_hash = Some(hash.clone());
+    }
+    records
+}
+
+fn run_bench(opts: &BenchOpts) -> ExitCode {
+    let baseline = match &opts.baseline {
+        Some(path) => match fs::read_to_string(path) {
+            Ok(text) => Some(bench::read_json(&text)),
+            Err(e) => {
+                eprintln!("error: {path}: {e}");
+                return ExitCode::FAILURE;
+            }
+        },
+        None => None,
+    };
+
+    let mut records = Vec::new();
+    let mut failed = false;
+
+    for solution in opts.days.iter().filter_map(|&d| years::get(opts.year, d)) {
+        let day = solution.day();
+        let input = match input::load(solution.year(), day, &opts.source) {
+            Ok(input) => input,
+            Err(e) => {
+                eprintln!("day {day}: {e}");
+                failed = true;
+                continue;
+            }
+        };
+
+        match bench::bench(solution, &input, solution.parts(), opts.runs) {
+            Ok(day_records) => records.extend(day_records),
+            Err(BenchError::Parse(e)) => {
+                eprintln!("{e}\n");
+                failed = true;
+            }
+            Err(BenchError::Panicked(phase)) => {
+                eprintln!("day {day}: {phase} failed");
+                failed = true;
+            }
+        }
+    }
+
+    let mut regressions = 0;
+    println!(
+        "{:>3}  {:<5}  {:>10}  {:>10}  {:>10}  {:>8}",
+        "Day", "Phase", "Min", "Median", "Max", "Change"
+    );
+    for r in &records {
+        let change = baseline.as_deref().and_then(|b| bench::change(r, b));
+        let regressed = change.is_some_and(|c| c * 100.0 > opts.threshold);
+        regressions += regressed as usize;
+
+        println!(
+            "{:>3}  {:<5}  {:>10}  {:>10}  {:>10}  {:>8}{}",
+            r.day,
+            r.phase,
+            format!("{:.2?}", r.min),
+            format!("{:.2?}", r.median),
+            format!("{:.2?}", r.max),
+            change.map_or_else(String::new, |c| format!("{:+.1}%", c * 100.0)),
+            if regressed { "  REGRESSION" } else { "" },
+        );
+    }
+
+    if let Err(e) = fs::write(&opts.out, bench::write_json(opts.runs, &records)) {
+        eprintln!("error: {}: {e}", opts.out);
+        failed = true;
+    }
+
+    if regressions > 0 {
+        eprintln!(
+            "\n{regressions} phase(s) slower than the baseline by more than {}%",
+            opts.threshold
+        );
+    }
+
+    if failed || regressions > 0 {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    }
+}
+
+fn verify(opts: &VerifyOpts) -> ExitCode {
+    let path = Path::new(&opts.answers);
+    let mut registry = match Registry::load(path) {
+        Ok(registry) => registry,
+        Err(e) => {
+            eprintln!("error: {e}");
+            return ExitCode::FAILURE;
+        }
+    };
+    let profile = opts.profile.as_deref().unwrap_or(answers::DEFAULT_PROFILE);
+
+    let mut rows = Vec::new();
+    let mut recorded = 0;
+
+    for solution in opts.days.iter().filter_map(|&d| years::get(opts.year, d)) {
+        for o in solve_day(solution, &[1, 2], &opts.source, &Params::default()) {
+            let status = match (&o.answer, &o.input_hash) {
+                (Ok(answer), Some(hash)) => {
+                    match registry.check(o.day, o.part, hash, profile, answer) {
+                        Verdict::Unknown if opts.record => {
+                            let mut keys = vec![Key::Hash(hash.clone())];
+                            keys.extend(opts.profile.clone().map(Key::Profile));
+                            for key in keys {
+                                registry.insert(Entry {
+                                    day: o.day,
+                                    part: o.part,
+                                    key,
+                                    answer: answer.clone(),
+                                });
+                            }
+                            recorded += 1;
+                            "recorded".to_string()
+                        }
+                        Verdict::Unknown => "unknown".to_string(),
+                        Verdict::Match => "match".to_string(),
+                        Verdict::Mismatch { expected } => format!("MISMATCH (expected {expected})"),
+                    }
+                }
+                (Err(e), _) => e.clone(),
+                (Ok(_), None) => unreachable!("answers need an input"),
+            };
+            rows.push((o, status));
+        }
+    }
+
+    let width = rows
+        .iter()
+        .map(|(o, _)| o.answer.as_ref().map_or(0, String::len))
+        .max()
+        .unwrap_or(0)
+        .max("Answer".len());
+
+    println!("{:>3}  {:>4}  {:<width$}  Status", "Day", "Part", "Answer");
+    for (o, status) in &rows {
+        println!(
+            "{:>3}  {:>4}  {:<width$}  {status}",
+            o.day,
+            o.part,
+            o.answer.as_deref().unwrap_or("-"),
+        );
+    }
+
+    if recorded > 0 {
+        if let Err(e) = fs::write(path, registry.to_string()) {
+            eprintln!("error: {}: {e}", path.display());
+            return ExitCode::FAILURE;
+        }
+        eprintln!("\nrecorded {recorded} answer(s) in {}", path.display());
+    }
+
+    let ok = |status: &str| matches!(status, "match" | "unknown" | "recorded");
+    if rows.iter().all(|(_, status)| ok(status)) {
+        ExitCode::SUCCESS
+    } else {
+        ExitCode::FAILURE
+    }
+}
+
+fn check(opts: &CheckOpts) -> ExitCode {
+    let mut failed = false;
+    let mut rows = Vec::new();
+
+    for solution in opts.days.iter().filter_map(|&d| years::get(opts.year, d)) {
+        let day = solution.day();
+        let input = match input::load(solution.year(), day, &opts.source) {
+            Ok(input) => input,
+            Err(e) => {
+                eprintln!("day {day}: {e}");
+                failed = true;
+                continue;
+            }
+        };
+
+        match solution.check(&input) {
+            Ok(assumptions) => rows.extend(assumptions.into_iter().map(|a| (day, a))),
+            Err(e) => {
+                eprintln!("{e}\n");
+                failed = true;
+            }
+        }
+    }
+
+    let width = rows
+        .iter()
+        .map(|(_, a)| a.what.len())
+        .max()
+        .unwrap_or(0)
+        .max("Assumption".len());
+
+    println!(
+        "{:>3}  {:<8}  {:<width$}  Found",
+        "Day", "Status", "Assumption"
+    );
+    for (day, a) in &rows {
+        let status = if a.holds { "holds" } else { "VIOLATED" };
+        println!("{day:>3}  {status:<8}  {:<width$}  {}", a.what, a.detail);
+    }
+
+    let violated = rows.iter().filter(|(_, a)| !a.holds).count();
+    if violated > 0 {
+        eprintln!("\n{violated} assumption(s) violated; answers for those days may be wrong");
+    }
+
+    if failed || violated > 0 {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    }
+}
+
+/// The parameters each day takes and their default for each part; `-` for a
+/// part that does not use one.
+fn list_params(year: u32, days: &[u32]) -> ExitCode {
+    let rows = days
+        .iter()
+        .filter_map(|&d| years::get(year, d))
+        .flat_map(|solution| solution.params().iter().map(|p| (solution.day(), p)))
+        .collect::<Vec<_>>();
+    let width = |column: &dyn Fn(&Param) -> &str, title: &str| {
+        rows.iter()
+            .map(|(_, p)| column(p).len())
+            .max()
+            .unwrap_or(0)
+            .max(title.len())
+    };
+    let name = width(&|p| p.name, "Param");
+    let part1 = width(&|p| p.part1.unwrap_or("-"), "Part 1");
+    let part2 = width(&|p| p.part2.unwrap_or("-"), "Part 2");
+
+    println!(
+        "{:>3}  {:<name$}  {:<part1$}  {:<part2$}  About",
+        "Day", "Param", "Part 1", "Part 2"
+    );
+    for (day, p) in &rows {
+        println!(
+            "{day:>3}  {:<name$}  {:<part1$}  {:<part2$}  {}",
+            p.name,
+            p.part1.unwrap_or("-"),
+            p.part2.unwrap_or("-"),
+            p.about,
+        );
+    }
+    ExitCode::SUCCESS
+}
+
+fn render(opts: &RenderOpts) -> ExitCode {
+    let mut renderer = Renderer::new(&opts.out, opts.format, opts.scale);
+    let mut failed = false;
+
+    for solution in opts.days.iter().filter_map(|&d| years::get(opts.year, d)) {
+        let day = solution.day();
+        let input = match input::load(solution.year(), day, &opts.source) {
+            Ok(input) => input,
+            Err(e) => {
+                eprintln!("day {day}: {e}");
+                failed = true;
+                continue;
+            }
+        };
+
+        let before = renderer.written().len();
+        match solution.render(&input, &mut renderer) {
+            Ok(()) => match renderer.written().len() - before {
+                0 => println!("day {day}: nothing to render"),
+                n => println!("day {day}: {n} file(s)"),
+            },
+            Err(RenderError::Parse(e)) => {
+                eprintln!("{e}\n");
+                failed = true;
+            }
+            Err(RenderError::Io(e)) => {
+                eprintln!("day {day}: {e}");
+                failed = true;
+            }
+        }
+    }
+
+    if !renderer.written().is_empty() {
+        println!(
+            "\nwrote {} file(s) to {}",
+            renderer.written().len(),
+            opts.out
+        );
+    }
+
+    if failed {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    }
+}
+
+fn generate(opts: &GenOpts) -> ExitCode {
+    match gen::input(opts.day, &mut Rng::new(opts.seed), opts.size) {
+        Some(input) => {
+            print!("{input}");
+            ExitCode::SUCCESS
+        }
+        None => {
+            eprintln!("error: no generator for day {}", opts.day);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn new_day(year: u32, day: u32) -> ExitCode {
+    match scaffold::new_day(Path::new("."), year, day) {
+        Ok(written) => {
+            for path in written {
+                println!("wrote {}", path.display());
+            }
+            println!(
+                "\nfill in examples/{year}/{day:02}/example.txt, then `aoc run {day} --year {year} --example example`"
+            );
+            ExitCode::SUCCESS
+        }
+        Err(e) => {
+            eprintln!("error: {e}");
+            ExitCode::FAILURE
+        }
+    }
 }
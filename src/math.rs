@@ -0,0 +1,230 @@
+//! Number theory for the "when do all the cycles line up" puzzles, without
+//! assuming the cycles start at zero or have coprime lengths, and exact
+//! linear algebra for the days that otherwise need a solver crate.
+
+use num::{BigRational, Zero};
+
+/// `(g, x, y)` with `a * x + b * y == g`, where `g` is the non-negative gcd.
+pub fn ext_gcd(a: i128, b: i128) -> (i128, i128, i128) {
+    let (mut old_r, mut r) = (a, b);
+    let (mut old_x, mut x) = (1, 0);
+    let (mut old_y, mut y) = (0, 1);
+    while r != 0 {
+        let q = old_r / r;
+        (old_r, r) = (r, old_r - q * r);
+        (old_x, x) = (x, old_x - q * x);
+        (old_y, y) = (y, old_y - q * y);
+    }
+    if old_r < 0 {
+        (-old_r, -old_x, -old_y)
+    } else {
+        (old_r, old_x, old_y)
+    }
+}
+
+/// Solves `x ≡ r (mod m)` for every `(r, m)`, with moduli that need not be
+/// coprime. Gives the smallest non-negative `x` and the lcm of the moduli
+/// it repeats with, or `None` if the congruences contradict each other.
+///
+/// # Panics
+///
+/// On a zero modulus, or if the lcm does not fit in a `u64`.
+pub fn crt(congruences: &[(u64, u64)]) -> Option<(u64, u64)> {
+    let (mut x, mut m) = (0_i128, 1_i128);
+    for &(r, n) in congruences {
+        assert!(n > 0, "zero modulus");
+        let (r, n) = (i128::from(r), i128::from(n));
+
+        // x + m * k ≡ r (mod n)  =>  (m / g) * k ≡ (r - x) / g (mod n / g)
+        let (g, inv, _) = ext_gcd(m, n);
+        if (r - x) % g != 0 {
+            return None;
+        }
+        let step = n / g;
+        let k = ((r - x) / g % step * inv % step + step) % step;
+        let lcm = m
+            .checked_mul(step)
+            .filter(|&lcm| lcm <= i128::from(u64::MAX))
+            .expect("lcm of the moduli overflows u64");
+        x += m * k;
+        m = lcm;
+        x = x.rem_euclid(m);
+    }
+    Some((u64::try_from(x).unwrap(), u64::try_from(m).unwrap()))
+}
+
+/// `offset, offset + period, offset + 2 * period, ...`; a period of zero is
+/// the single value `offset`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Progression {
+    pub offset: u64,
+    pub period: u64,
+}
+
+impl Progression {
+    pub fn new(offset: u64, period: u64) -> Self {
+        Self { offset, period }
+    }
+
+    pub fn contains(&self, x: u64) -> bool {
+        match self.period {
+            0 => x == self.offset,
+            p => x >= self.offset && (x - self.offset).is_multiple_of(p),
+        }
+    }
+
+    /// The values in both, or `None` if they never meet.
+    pub fn intersect(&self, other: &Self) -> Option<Self> {
+        match (self.period, other.period) {
+            (0, _) => other.contains(self.offset).then_some(*self),
+            (_, 0) => self.contains(other.offset).then_some(*other),
+            (p, q) => {
+                let (x, m) = crt(&[(self.offset % p, p), (other.offset % q, q)])?;
+                // first solution at or after both starts
+                let start = self.offset.max(other.offset);
+                let offset = if x >= start {
+                    x
+                } else {
+                    x + (start - x).div_ceil(m) * m
+                };
+                Some(Self::new(offset, m))
+            }
+        }
+    }
+}
+
+/// The values common to every progression; `None` if there are none, or
+/// if `progressions` is empty.
+pub fn combine(progressions: impl IntoIterator<Item = Progression>) -> Option<Progression> {
+    let mut progressions = progressions.into_iter();
+    let first = progressions.next()?;
+    progressions.try_fold(first, |acc, p| acc.intersect(&p))
+}
+
+/// Solves `a * x = b` for square `a` by Gaussian elimination over exact
+/// rationals, so large puzzle coordinates lose nothing to rounding. `None`
+/// if `a` is singular.
+///
+/// # Panics
+///
+/// If `a` is not `b.len()` rows of `b.len()` entries.
+pub fn solve_linear(
+    mut a: Vec<Vec<BigRational>>,
+    mut b: Vec<BigRational>,
+) -> Option<Vec<BigRational>> {
+    let n = b.len();
+    assert!(
+        a.len() == n && a.iter().all(|row| row.len() == n),
+        "not square"
+    );
+
+    for col in 0..n {
+        let pivot = (col..n).find(|&r| !a[r][col].is_zero())?;
+        a.swap(col, pivot);
+        b.swap(col, pivot);
+        let (pivot_row, pivot_b) = (a[col].clone(), b[col].clone());
+        for r in 0..n {
+            if r == col || a[r][col].is_zero() {
+                continue;
+            }
+            let factor = &a[r][col] / &pivot_row[col];
+            for (x, p) in a[r][col..].iter_mut().zip(&pivot_row[col..]) {
+                *x -= &factor * p;
+            }
+            b[r] -= &factor * &pivot_b;
+        }
+    }
+
+    Some(
+        b.into_iter()
+            .zip(a)
+            .enumerate()
+            .map(|(i, (b, row))| b / &row[i])
+            .collect(),
+    )
+}
+
+#[test]
+fn gcd_and_crt() {
+    for (a, b) in [(240, 46), (46, 240), (-12, 18), (7, 0), (0, 0)] {
+        let (g, x, y) = ext_gcd(a, b);
+        assert_eq!(a * x + b * y, g);
+        assert_eq!(g, num::integer::gcd(a, b));
+    }
+
+    for m in 1..=12 {
+        for n in 1..=12 {
+            for r in 0..m {
+                for s in 0..n {
+                    let brute = (0..m * n).find(|x| x % m == r && x % n == s);
+                    let solved = crt(&[(r, m), (s, n)]);
+                    assert_eq!(solved.map(|(x, _)| x), brute, "{r} mod {m}, {s} mod {n}");
+                    if let Some((_, lcm)) = solved {
+                        assert_eq!(lcm, num::integer::lcm(m, n));
+                    }
+                }
+            }
+        }
+    }
+    assert_eq!(crt(&[]), Some((0, 1)));
+
+    // the lcm of the first two is past i64::MAX
+    let (p, q) = (4_294_967_291, 4_294_967_279);
+    let (x, lcm) = crt(&[(1, p), (2, q), (0, 1)]).unwrap();
+    assert_eq!((x % p, x % q, lcm), (1, 2, p * q));
+}
+
+#[test]
+fn progressions() {
+    let brute = |ps: &[Progression]| (0..2000).find(|&x| ps.iter().all(|p| p.contains(x)));
+
+    for a in 0..10 {
+        for p in [0, 3, 4, 6] {
+            for b in 0..10 {
+                for q in [0, 2, 5, 6] {
+                    let ps = [Progression::new(a, p), Progression::new(b, q)];
+                    assert_eq!(combine(ps).map(|c| c.offset), brute(&ps), "{ps:?}");
+                }
+            }
+        }
+    }
+
+    // lined up at zero, the plain lcm
+    let cycles = [3, 4, 5].map(|l| Progression::new(l, l));
+    assert_eq!(combine(cycles), Some(Progression::new(60, 60)));
+    // offset cycles
+    let cycles = [Progression::new(2, 3), Progression::new(3, 4)];
+    assert_eq!(combine(cycles), Some(Progression::new(11, 12)));
+    assert_eq!(combine([]), None);
+}
+
+#[test]
+fn linear_systems() {
+    let q = |rows: &[&[i64]]| {
+        rows.iter()
+            .map(|row| {
+                row.iter()
+                    .map(|&x| BigRational::from_integer(x.into()))
+                    .collect()
+            })
+            .collect::<Vec<Vec<_>>>()
+    };
+
+    // x + 2y = 5, 3x - y = 1, with the first pivot zero after a swap
+    let a = q(&[&[0, 0, 1], &[1, 2, 0], &[3, -1, 0]]);
+    let b = q(&[&[7, 5, 1]]).remove(0);
+    let x = solve_linear(a, b).unwrap();
+    assert_eq!(x, q(&[&[1, 2, 7]]).remove(0));
+
+    // fractional, and beyond what an f64 holds exactly
+    let big = 1_i64 << 60;
+    let a = q(&[&[3, 0], &[0, 2]]);
+    let x = solve_linear(a, q(&[&[1, big + 1]]).remove(0)).unwrap();
+    assert_eq!(x[0], BigRational::new(1.into(), 3.into()));
+    assert_eq!(x[1], BigRational::new((big + 1).into(), 2.into()));
+
+    assert_eq!(
+        solve_linear(q(&[&[1, 2], &[2, 4]]), q(&[&[1, 2]]).remove(0)),
+        None
+    );
+}
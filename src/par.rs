@@ -0,0 +1,203 @@
+//! Spreading independent pieces of work, such as the rows of day 12 or the
+//! entry beams of day 16, over several threads. Workers pull items one at a
+//! time from a shared iterator, so uneven items still balance, and results
+//! come back in the iterator's order whatever the thread count.
+//!
+//! The thread count is process-wide (`aoc run --threads N`) and starts at
+//! 1, which runs everything on the calling thread with no overhead. What
+//! the workers [count](crate::stats) is added to the calling thread's tally.
+
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Mutex;
+use std::thread;
+
+use crate::stats;
+
+static THREADS: AtomicUsize = AtomicUsize::new(1);
+
+/// Sets the number of threads later calls use; 0 is taken as 1.
+pub fn set_threads(n: usize) {
+    THREADS.store(n.max(1), Ordering::Relaxed);
+}
+
+pub fn threads() -> usize {
+    THREADS.load(Ordering::Relaxed)
+}
+
+/// `items.map(f).collect()`, spread over [`threads`] threads.
+pub fn map<I, R>(items: I, f: impl Fn(I::Item) -> R + Sync) -> Vec<R>
+where
+    I: Iterator + Send,
+    I::Item: Send,
+    R: Send,
+{
+    map_with(items, || (), |_, item| f(item))
+}
+
+/// Like [`map`], but each thread gets its own state from `init` to work
+/// with, e.g. a memo that cannot be shared.
+pub fn map_with<I, S, R>(
+    items: I,
+    init: impl Fn() -> S + Sync,
+    f: impl Fn(&mut S, I::Item) -> R + Sync,
+) -> Vec<R>
+where
+    I: Iterator + Send,
+    I::Item: Send,
+    R: Send,
+{
+    map_on(threads(), items, init, f)
+}
+
+/// The first `Some` that `f` gives in the order of `items`, as found
+/// sequentially, though later items may be tried speculatively.
+pub fn find_map_first<I, R>(items: I, f: impl Fn(I::Item) -> Option<R> + Sync) -> Option<R>
+where
+    I: Iterator + Send,
+    I::Item: Send,
+    R: Send,
+{
+    find_map_first_on(threads(), items, f)
+}
+
+fn map_on<I, S, R>(
+    threads: usize,
+    items: I,
+    init: impl Fn() -> S + Sync,
+    f: impl Fn(&mut S, I::Item) -> R + Sync,
+) -> Vec<R>
+where
+    I: Iterator + Send,
+    I::Item: Send,
+    R: Send,
+{
+    if threads <= 1 {
+        let mut state = init();
+        return items.map(|item| f(&mut state, item)).collect();
+    }
+
+    let items = Mutex::new(items.enumerate());
+    let mut results = thread::scope(|scope| {
+        let workers = (0..threads)
+            .map(|_| {
+                scope.spawn(|| {
+                    let mut state = init();
+                    let mut done = Vec::new();
+                    // the lock is released before `f` runs
+                    while let Some((i, item)) = next(&items) {
+                        done.push((i, f(&mut state, item)));
+                    }
+                    (done, stats::take())
+                })
+            })
+            .collect::<Vec<_>>();
+        workers
+            .into_iter()
+            .flat_map(|w| {
+                let (done, counted) = w.join().unwrap_or_else(|e| std::panic::resume_unwind(e));
+                stats::merge(counted);
+                done
+            })
+            .collect::<Vec<_>>()
+    });
+
+    results.sort_unstable_by_key(|&(i, _)| i);
+    results.into_iter().map(|(_, r)| r).collect()
+}
+
+fn find_map_first_on<I, R>(
+    threads: usize,
+    mut items: I,
+    f: impl Fn(I::Item) -> Option<R> + Sync,
+) -> Option<R>
+where
+    I: Iterator + Send,
+    I::Item: Send,
+    R: Send,
+{
+    if threads <= 1 {
+        return items.find_map(f);
+    }
+
+    // index of the earliest item found so far; nothing after it is started
+    let first = AtomicUsize::new(usize::MAX);
+    let items = Mutex::new(items.enumerate());
+    let found = thread::scope(|scope| {
+        let workers = (0..threads)
+            .map(|_| {
+                scope.spawn(|| {
+                    let mut found = None;
+                    while let Some((i, item)) = next(&items) {
+                        if i > first.load(Ordering::Relaxed) {
+                            break;
+                        }
+                        if let Some(r) = f(item) {
+                            first.fetch_min(i, Ordering::Relaxed);
+                            found = Some((i, r));
+                            break;
+                        }
+                    }
+                    (found, stats::take())
+                })
+            })
+            .collect::<Vec<_>>();
+        workers
+            .into_iter()
+            .filter_map(|w| {
+                let (found, counted) = w.join().unwrap_or_else(|e| std::panic::resume_unwind(e));
+                stats::merge(counted);
+                found
+            })
+            .collect::<Vec<_>>()
+    });
+
+    found.into_iter().min_by_key(|&(i, _)| i).map(|(_, r)| r)
+}
+
+fn next<I: Iterator>(items: &Mutex<I>) -> Option<I::Item> {
+    // a worker that panicked mid-`next` poisons the lock; the scope rethrows
+    // its panic anyway
+    items.lock().ok()?.next()
+}
+
+#[test]
+fn same_results_on_any_thread_count() {
+    let collatz = |mut n: u64| {
+        let mut steps = 0;
+        while n != 1 {
+            n = if n.is_multiple_of(2) {
+                n / 2
+            } else {
+                3 * n + 1
+            };
+            steps += 1;
+        }
+        steps
+    };
+
+    let expected = (1..2000).map(collatz).collect::<Vec<_>>();
+    for threads in [1, 2, 3, 8] {
+        assert_eq!(map_on(threads, 1..2000, || (), |_, n| collatz(n)), expected);
+
+        // per-thread state is never shared
+        let counted = map_on(
+            threads,
+            0..100,
+            || 0,
+            |seen, n| {
+                *seen += 1;
+                (n, *seen)
+            },
+        );
+        assert!(counted
+            .iter()
+            .enumerate()
+            .all(|(i, &(n, seen))| n == i && seen >= 1));
+        assert!(counted.iter().filter(|&&(_, seen)| seen == 1).count() <= threads);
+
+        // the first long chain, not just any
+        let long = find_map_first_on(threads, 1..2000_u64, |n| (collatz(n) > 100).then_some(n));
+        assert_eq!(long, Some(27));
+        assert_eq!(find_map_first_on(threads, 1..50_u64, |_| None::<()>), None);
+    }
+}
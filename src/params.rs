@@ -0,0 +1,205 @@
+//! Numbers a puzzle gives in its text rather than in the input, like day
+//! 11's expansion factor or day 21's step count, which its examples change.
+//! A day declares the ones it has in
+//! [`Solution::PARAMS`](crate::Solution::PARAMS) with the puzzle's values
+//! as defaults, and reads them by name in
+//! [`Solution::part1_with`](crate::Solution::part1_with). Example fixtures
+//! and `aoc run --param name=value` change them.
+
+use std::fmt;
+
+/// One of a puzzle's parameters and the value each part uses by default.
+/// A part without a default does not use it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Param {
+    pub name: &'static str,
+    pub about: &'static str,
+    pub part1: Option<&'static str>,
+    pub part2: Option<&'static str>,
+}
+
+impl Param {
+    pub const fn new(name: &'static str, about: &'static str) -> Self {
+        Self {
+            name,
+            about,
+            part1: None,
+            part2: None,
+        }
+    }
+
+    pub const fn part1(self, default: &'static str) -> Self {
+        Self {
+            part1: Some(default),
+            ..self
+        }
+    }
+
+    pub const fn part2(self, default: &'static str) -> Self {
+        Self {
+            part2: Some(default),
+            ..self
+        }
+    }
+
+    pub const fn both(self, default: &'static str) -> Self {
+        self.part1(default).part2(default)
+    }
+
+    pub fn default_for(&self, part: u32) -> Option<&'static str> {
+        match part {
+            1 => self.part1,
+            _ => self.part2,
+        }
+    }
+}
+
+/// Parameter values by name, as given, e.g. by an example fixture.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Params(Vec<(String, String)>);
+
+impl Params {
+    /// Sets `name`, replacing any earlier value.
+    pub fn set(&mut self, name: &str, value: &str) {
+        match self.0.iter_mut().find(|(n, _)| n == name) {
+            Some((_, v)) => *v = value.to_string(),
+            None => self.0.push((name.to_string(), value.to_string())),
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &str)> {
+        self.0.iter().map(|(n, v)| (n.as_str(), v.as_str()))
+    }
+
+    /// What `part` runs with: the defaults `declared` for it, replaced by
+    /// any set here.
+    pub fn resolve(&self, declared: &[Param], part: u32) -> Self {
+        let mut resolved = Self::default();
+        for param in declared {
+            if let Some(default) = param.default_for(part) {
+                let given = self.0.iter().find(|(n, _)| n == param.name);
+                resolved.set(param.name, given.map_or(default, |(_, v)| v));
+            }
+        }
+        resolved
+    }
+
+    /// What `part` runs with by default.
+    pub fn defaults(declared: &[Param], part: u32) -> Self {
+        Self::default().resolve(declared, part)
+    }
+
+    /// The names set here that are not among `declared`.
+    pub fn unknown<'a>(&'a self, declared: &'a [Param]) -> impl Iterator<Item = &'a str> {
+        self.iter()
+            .map(|(name, _)| name)
+            .filter(|name| declared.iter().all(|p| p.name != *name))
+    }
+
+    /// The value of `name`, which the runner has already
+    /// [resolved](Self::resolve).
+    ///
+    /// # Panics
+    ///
+    /// If `name` is not set or its value cannot be read as a `T`, which
+    /// fails the part reading it.
+    pub fn get<T: Value>(&self, name: &str) -> T {
+        self.get_valid(name, |_| true)
+    }
+
+    /// Like [`get`](Self::get), but the value must also be one `valid`
+    /// accepts, such as an expansion factor of at least 1.
+    pub fn get_valid<T: Value>(&self, name: &str, valid: impl Fn(&T) -> bool) -> T {
+        match self.0.iter().find(|(n, _)| n == name) {
+            Some((_, v)) => T::read(v)
+                .filter(valid)
+                .unwrap_or_else(|| panic!("invalid {name} `{v}`")),
+            None => panic!("no parameter {name}"),
+        }
+    }
+}
+
+impl fmt::Display for Params {
+    /// `name=value` pairs separated by `;`, which no [`Value`] holds, unlike
+    /// the `,` of a pair
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, (name, value)) in self.0.iter().enumerate() {
+            if i > 0 {
+                f.write_str(";")?;
+            }
+            write!(f, "{name}={value}")?;
+        }
+        Ok(())
+    }
+}
+
+/// What a parameter can hold.
+pub trait Value: Sized {
+    fn read(s: &str) -> Option<Self>;
+}
+
+macro_rules! value_from_str {
+    ($($t:ty),*) => {$(
+        impl Value for $t {
+            fn read(s: &str) -> Option<Self> {
+                s.trim().parse().ok()
+            }
+        }
+    )*};
+}
+
+value_from_str!(u32, u64, usize, isize, i64, f64);
+
+impl<T: Value> Value for (T, T) {
+    /// `a,b`, or `lo..hi` for an inclusive range
+    fn read(s: &str) -> Option<Self> {
+        let (a, b) = s.split_once("..").or_else(|| s.split_once(','))?;
+        Some((T::read(a)?, T::read(b)?))
+    }
+}
+
+#[test]
+fn values() {
+    let mut params = Params::default();
+    params.set("steps", "6");
+    params.set("area", "7..27");
+    params.set("steps", "10");
+    params.set("start", "2, 0");
+
+    assert_eq!(params.get::<usize>("steps"), 10);
+    assert_eq!(params.get::<(f64, f64)>("area"), (7.0, 27.0));
+    assert_eq!(params.get::<(usize, usize)>("start"), (2, 0));
+    assert_eq!(params.to_string(), "steps=10;area=7..27;start=2, 0");
+
+    let bad = std::panic::catch_unwind(|| params.get::<u32>("area")).unwrap_err();
+    assert_eq!(*bad.downcast::<String>().unwrap(), "invalid area `7..27`");
+}
+
+#[test]
+fn defaults_per_part() {
+    const DECLARED: &[Param] = &[
+        Param::new("steps", "").part1("64").part2("26501365"),
+        Param::new("area", "").part1("2e14..4e14"),
+    ];
+
+    assert_eq!(
+        Params::defaults(DECLARED, 1).to_string(),
+        "steps=64;area=2e14..4e14"
+    );
+    assert_eq!(Params::defaults(DECLARED, 2).to_string(), "steps=26501365");
+
+    let mut given = Params::default();
+    given.set("area", "7..27");
+    given.set("cycles", "3");
+    assert_eq!(
+        given.resolve(DECLARED, 1).to_string(),
+        "steps=64;area=7..27"
+    );
+    // a part that does not use a parameter ignores it
+    assert_eq!(given.resolve(DECLARED, 2).to_string(), "steps=26501365");
+    assert_eq!(given.unknown(DECLARED).collect::<Vec<_>>(), ["cycles"]);
+}
@@ -0,0 +1,202 @@
+//! Parse errors that point back into the puzzle input.
+//!
+//! Parsers work on subslices of the input, so an error only needs to keep
+//! the offending token. Its position is recovered afterwards from the
+//! token's address relative to the whole input (the same trick as nom's
+//! `Offset`), which keeps line/column bookkeeping out of every day's parser.
+
+use std::fmt;
+use std::str::FromStr;
+
+use crate::grid::Grid;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    pub day: Option<u32>,
+    pub expected: String,
+    pub found: String,
+    /// 1-based; 0 until [`ParseError::locate`] finds the token
+    pub line: usize,
+    /// 1-based, in chars
+    pub column: usize,
+    source_line: String,
+    width: usize,
+    addr: usize,
+}
+
+impl ParseError {
+    /// `token` must be a subslice of the input for the position to be found
+    /// later; an empty slice marks the spot where something was missing.
+    pub fn new(token: &str, expected: impl Into<String>) -> Self {
+        Self {
+            day: None,
+            expected: expected.into(),
+            found: token.to_string(),
+            line: 0,
+            column: 0,
+            source_line: String::new(),
+            width: token.chars().count().max(1),
+            addr: token.as_ptr() as usize,
+        }
+    }
+
+    /// Fills in the day and, if the token lies within `input`, its line and
+    /// column.
+    pub fn locate(mut self, day: u32, input: &str) -> Self {
+        self.day = Some(day);
+
+        let start = input.as_ptr() as usize;
+        if self.line == 0 && (start..=start + input.len()).contains(&self.addr) {
+            let offset = self.addr - start;
+            let line_start = input[..offset].rfind('\n').map_or(0, |i| i + 1);
+            let line_end = input[offset..]
+                .find('\n')
+                .map_or(input.len(), |i| offset + i);
+
+            self.line = input[..offset].matches('\n').count() + 1;
+            self.column = input[line_start..offset].chars().count() + 1;
+            self.source_line = input[line_start..line_end]
+                .trim_end_matches('\r')
+                .to_string();
+        }
+
+        self
+    }
+
+    /// Positions the error within a single line whose number is known, for
+    /// parsers that never see the whole input.
+    pub fn locate_in_line(mut self, day: u32, line_no: usize, line: &str) -> Self {
+        self = self.locate(day, line);
+        if self.line != 0 {
+            self.line = line_no;
+        }
+        self
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let found = match self.found.as_str() {
+            "" => "end of line".to_string(),
+            s => format!("`{s}`"),
+        };
+
+        write!(f, "parse error")?;
+        if let Some(day) = self.day {
+            write!(f, " in day {day}")?;
+        }
+        if self.line != 0 {
+            write!(f, " at line {}, column {}", self.line, self.column)?;
+        }
+        write!(f, ": expected {}, found {found}", self.expected)?;
+
+        if self.line != 0 {
+            let gutter = " ".repeat(self.line.to_string().len());
+            write!(
+                f,
+                "\n{gutter} |\n{} | {}\n{gutter} | {}{}",
+                self.line,
+                self.source_line,
+                " ".repeat(self.column - 1),
+                "^".repeat(self.width),
+            )?;
+        }
+
+        Ok(())
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+pub fn split_once<'a>(s: &'a str, sep: &str) -> Result<(&'a str, &'a str), ParseError> {
+    s.split_once(sep)
+        .ok_or_else(|| ParseError::new(&s[s.len()..], format!("`{sep}`")))
+}
+
+pub fn number<T: FromStr>(s: &str) -> Result<T, ParseError> {
+    s.parse().map_err(|_| ParseError::new(s, "a number"))
+}
+
+/// The next item of `it`, or an error pointing just past `after`.
+pub fn next<'a>(
+    it: &mut impl Iterator<Item = &'a str>,
+    after: &'a str,
+    expected: &str,
+) -> Result<&'a str, ParseError> {
+    it.next()
+        .ok_or_else(|| ParseError::new(&after[after.len()..], expected))
+}
+
+/// Rectangular grid of cells; `cell` rejects characters by returning `None`.
+pub fn grid<T>(
+    input: &str,
+    expected: &str,
+    cell: impl Fn(char) -> Option<T>,
+) -> Result<Grid<T>, ParseError> {
+    let mut grid: Vec<Vec<T>> = Vec::new();
+
+    for line in input.lines() {
+        let row = line
+            .char_indices()
+            .map(|(i, ch)| {
+                cell(ch).ok_or_else(|| ParseError::new(&line[i..i + ch.len_utf8()], expected))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        if let Some(first) = grid.first() {
+            if row.len() != first.len() {
+                return Err(ParseError::new(
+                    line,
+                    format!("a row of {} cells", first.len()),
+                ));
+            }
+        }
+        grid.push(row);
+    }
+
+    if grid.is_empty() || grid[0].is_empty() {
+        return Err(ParseError::new(input, "a non-empty grid"));
+    }
+
+    Ok(Grid::from_rows(grid).unwrap())
+}
+
+/// Grid of the raw characters, checked against `allowed`.
+pub fn char_grid(input: &str, allowed: &str) -> Result<Grid<char>, ParseError> {
+    let expected = format!("one of `{allowed}`");
+    grid(input, &expected, |ch| allowed.contains(ch).then_some(ch))
+}
+
+#[test]
+fn locate_token() {
+    let input = "Game 1: 3 blue\nGame 2 4 red\n";
+    let line = input.lines().nth(1).unwrap();
+    let err = split_once(line, ": ").unwrap_err().locate(2, input);
+
+    assert_eq!((err.line, err.column), (2, 13));
+    assert_eq!(
+        err.to_string(),
+        "parse error in day 2 at line 2, column 13: expected `: `, found end of line
+  |
+2 | Game 2 4 red
+  |             ^"
+    );
+
+    let err = number::<u32>(&line[9..]).unwrap_err().locate(2, input);
+    assert_eq!((err.line, err.column, err.found.as_str()), (2, 10, "red"));
+    assert!(err.expected.contains("number"));
+}
+
+#[test]
+fn grid_errors() {
+    assert_eq!(char_grid("#.\n.#", ".#").unwrap().rows(), 2);
+
+    let input = "#.\n.x";
+    let err = char_grid(input, ".#").unwrap_err().locate(13, input);
+    assert_eq!((err.line, err.column, err.found.as_str()), (2, 2, "x"));
+
+    let input = "#.\n.";
+    let err = char_grid(input, ".#").unwrap_err().locate(13, input);
+    assert_eq!(err.line, 2);
+    assert!(char_grid("", ".#").is_err());
+}
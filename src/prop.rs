@@ -0,0 +1,246 @@
+//! Differential testing: a fast solution and a slow reference run side by
+//! side on many small random inputs, and the first input they disagree on
+//! is shrunk to a small one before it is reported.
+//!
+//! Days register their reference in a test next to the solution, e.g.
+//! day 12's memoized `dp` against enumerating every arrangement:
+//!
+//! ```ignore
+//! prop::agree(300, random_row, arrangements, enumerate);
+//! ```
+//!
+//! Case `i` is generated from seed `i`, so a reported seed gives back the
+//! original input.
+
+use std::fmt::{self, Debug};
+use std::panic::{self, AssertUnwindSafe};
+
+use crate::gen::Rng;
+
+/// Evaluations of the property allowed while shrinking one counterexample.
+const SHRINK_BUDGET: usize = 10_000;
+
+/// A test input that knows how to make smaller versions of itself.
+pub trait Shrink: Clone + Debug {
+    /// Candidates that are simpler than `self`, most aggressive first.
+    /// Each must still be a valid input for the property.
+    fn shrink(&self) -> Vec<Self>;
+}
+
+/// An input the property fails on, as generated and after shrinking.
+#[derive(Debug, Clone)]
+pub struct Counterexample<T> {
+    pub seed: u64,
+    pub original: T,
+    pub shrunk: T,
+    /// how the property fails on `shrunk`
+    pub failure: String,
+    pub steps: usize,
+}
+
+impl<T: Debug> fmt::Display for Counterexample<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "fails for seed {}: {}", self.seed, self.failure)?;
+        writeln!(f, "shrunk in {} steps to {:#?}", self.steps, self.shrunk)?;
+        write!(f, "from {:#?}", self.original)
+    }
+}
+
+/// Panics with a shrunk counterexample unless `fast` and `reference` give
+/// the same result on `cases` inputs from `gen`.
+pub fn agree<T, R>(
+    cases: u64,
+    gen: impl FnMut(&mut Rng) -> T,
+    fast: impl Fn(&T) -> R,
+    reference: impl Fn(&T) -> R,
+) where
+    T: Shrink,
+    R: PartialEq + Debug,
+{
+    check(cases, gen, |input| {
+        let (fast, reference) = (fast(input), reference(input));
+        if fast == reference {
+            Ok(())
+        } else {
+            Err(format!("got {fast:?}, reference gives {reference:?}"))
+        }
+    });
+}
+
+/// Panics with a shrunk counterexample unless `property` holds on `cases`
+/// inputs from `gen`.
+pub fn check<T: Shrink>(
+    cases: u64,
+    gen: impl FnMut(&mut Rng) -> T,
+    property: impl Fn(&T) -> Result<(), String>,
+) {
+    if let Some(counterexample) = find(cases, gen, property) {
+        panic!("{counterexample}");
+    }
+}
+
+/// The first of `cases` inputs from `gen` that `property` fails on (or
+/// panics on), shrunk as far as it still fails.
+pub fn find<T: Shrink>(
+    cases: u64,
+    mut gen: impl FnMut(&mut Rng) -> T,
+    property: impl Fn(&T) -> Result<(), String>,
+) -> Option<Counterexample<T>> {
+    let fails = |input: &T| match panic::catch_unwind(AssertUnwindSafe(|| property(input))) {
+        Ok(result) => result.err(),
+        Err(payload) => Some(match payload.downcast::<String>() {
+            Ok(message) => format!("panicked: {message}"),
+            Err(payload) => match payload.downcast::<&str>() {
+                Ok(message) => format!("panicked: {message}"),
+                Err(_) => "panicked".to_string(),
+            },
+        }),
+    };
+
+    (0..cases).find_map(|seed| {
+        let original = gen(&mut Rng::new(seed));
+        let mut failure = fails(&original)?;
+
+        // greedily take the first smaller input that still fails
+        let (mut shrunk, mut steps, mut budget) = (original.clone(), 0, SHRINK_BUDGET);
+        'shrinking: while budget > 0 {
+            for candidate in shrunk.shrink() {
+                if budget == 0 {
+                    break 'shrinking;
+                }
+                budget -= 1;
+                if let Some(f) = fails(&candidate) {
+                    (shrunk, failure, steps) = (candidate, f, steps + 1);
+                    continue 'shrinking;
+                }
+            }
+            break;
+        }
+
+        Some(Counterexample {
+            seed,
+            original,
+            shrunk,
+            failure,
+            steps,
+        })
+    })
+}
+
+macro_rules! shrink_unsigned {
+    ($($t:ty),*) => {$(
+        impl Shrink for $t {
+            /// towards zero: zero itself, then half, then one less
+            fn shrink(&self) -> Vec<Self> {
+                let mut out = Vec::new();
+                for x in [0, self / 2, self.saturating_sub(1)] {
+                    if x < *self && !out.contains(&x) {
+                        out.push(x);
+                    }
+                }
+                out
+            }
+        }
+    )*};
+}
+
+shrink_unsigned!(u8, u32, u64, usize);
+
+impl Shrink for bool {
+    fn shrink(&self) -> Vec<Self> {
+        if *self {
+            vec![false]
+        } else {
+            Vec::new()
+        }
+    }
+}
+
+impl<T: Shrink> Shrink for Vec<T> {
+    /// Without runs of elements, halves first down to single ones, then with
+    /// one element shrunk.
+    fn shrink(&self) -> Vec<Self> {
+        let mut out = Vec::new();
+        let mut run = self.len() / 2;
+        while run > 0 {
+            for start in (0..=self.len() - run).step_by(run) {
+                let mut v = self[..start].to_vec();
+                v.extend_from_slice(&self[start + run..]);
+                out.push(v);
+            }
+            run /= 2;
+        }
+        if self.len() == 1 {
+            out.push(Vec::new());
+        }
+        for (i, x) in self.iter().enumerate() {
+            for smaller in x.shrink() {
+                let mut v = self.clone();
+                v[i] = smaller;
+                out.push(v);
+            }
+        }
+        out
+    }
+}
+
+impl<A: Shrink, B: Shrink> Shrink for (A, B) {
+    fn shrink(&self) -> Vec<Self> {
+        let (a, b) = self;
+        let mut out = a
+            .shrink()
+            .into_iter()
+            .map(|a| (a, b.clone()))
+            .collect::<Vec<_>>();
+        out.extend(b.shrink().into_iter().map(|b| (a.clone(), b)));
+        out
+    }
+}
+
+#[test]
+fn shrinks_to_a_minimal_counterexample() {
+    // "no element is at least 10", which a sum over 20 of them soon breaks
+    let gen = |rng: &mut Rng| {
+        (0..rng.range(0..=20))
+            .map(|_| rng.range(0..=12) as u64)
+            .collect::<Vec<_>>()
+    };
+    let found = find(100, gen, |v: &Vec<u64>| {
+        if v.iter().all(|&x| x < 10) {
+            Ok(())
+        } else {
+            Err(format!("{} elements", v.len()))
+        }
+    })
+    .unwrap();
+    assert_eq!(found.shrunk, vec![10]);
+    assert!(found.original.len() > 1);
+    assert_eq!(found.failure, "1 elements");
+
+    // panics count as failures too
+    let found = find(100, gen, |v: &Vec<u64>| {
+        assert!(v.len() < 3, "too long");
+        Ok(())
+    })
+    .unwrap();
+    assert_eq!(found.shrunk, vec![0, 0, 0]);
+    assert!(found.failure.contains("too long"), "{}", found.failure);
+
+    assert!(find(100, gen, |_| Ok(())).is_none());
+}
+
+#[test]
+fn agreeing_implementations_pass() {
+    let gen = |rng: &mut Rng| (rng.range(0..=1000) as u64, rng.range(0..=1000) as u64);
+    agree(200, gen, |&(a, b)| a * b, |&(a, b)| (0..b).map(|_| a).sum());
+
+    let reported = panic::catch_unwind(|| {
+        agree(200, gen, |&(a, b)| a.max(b), |&(a, b)| a.max(b).min(900));
+    })
+    .unwrap_err();
+    let message = reported.downcast::<String>().unwrap();
+    assert!(
+        message.contains("shrunk in") && message.contains("got 901, reference gives 900"),
+        "{message}"
+    );
+}
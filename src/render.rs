@@ -0,0 +1,350 @@
+//! Pictures of grid puzzles written to disk, one pixel block per cell, for
+//! looking at what a solution did instead of printing grids. Simulations
+//! can write numbered frames to assemble into an animation elsewhere.
+//!
+//! PNGs are written uncompressed so no compression crate is needed; they
+//! are larger than they could be but open anywhere.
+
+use std::collections::HashMap;
+use std::fmt::Write as _;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use crate::grid::{Grid, Pos};
+use crate::ParseError;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Rgb(pub u8, pub u8, pub u8);
+
+impl Rgb {
+    pub const BLACK: Rgb = Rgb(0, 0, 0);
+    pub const WHITE: Rgb = Rgb(255, 255, 255);
+    pub const GREY: Rgb = Rgb(128, 128, 128);
+    pub const DARK: Rgb = Rgb(32, 32, 40);
+    pub const RED: Rgb = Rgb(220, 50, 47);
+    pub const GREEN: Rgb = Rgb(80, 200, 90);
+    pub const BLUE: Rgb = Rgb(60, 110, 220);
+    pub const YELLOW: Rgb = Rgb(250, 210, 60);
+
+    /// `t` of the way from `self` to `other`, for `t` in `0..=1`.
+    pub fn blend(self, other: Rgb, t: f64) -> Rgb {
+        let mix =
+            |a: u8, b: u8| (a as f64 + (b as f64 - a as f64) * t.clamp(0.0, 1.0)).round() as u8;
+        Rgb(
+            mix(self.0, other.0),
+            mix(self.1, other.1),
+            mix(self.2, other.2),
+        )
+    }
+}
+
+/// one colour per grid cell
+pub type Image = Grid<Rgb>;
+
+/// Colours `grid` cell by cell.
+pub fn image<T>(grid: &Grid<T>, mut colour: impl FnMut(Pos, &T) -> Rgb) -> Image {
+    let mut image = Grid::new(grid.rows(), grid.cols(), Rgb::BLACK);
+    for (pos, cell) in grid.iter() {
+        image[pos] = colour(pos, cell);
+    }
+    image
+}
+
+/// Colours the given cells, e.g. a route over a picture of the map.
+pub fn paint(image: &mut Image, cells: impl IntoIterator<Item = Pos>, colour: Rgb) {
+    for pos in cells {
+        if let Some(pixel) = image.get_mut(pos) {
+            *pixel = colour;
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+    Png,
+    Ppm,
+    Svg,
+}
+
+impl Format {
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "png" => Some(Self::Png),
+            "ppm" => Some(Self::Ppm),
+            "svg" => Some(Self::Svg),
+            _ => None,
+        }
+    }
+
+    pub fn extension(self) -> &'static str {
+        match self {
+            Self::Png => "png",
+            Self::Ppm => "ppm",
+            Self::Svg => "svg",
+        }
+    }
+
+    /// The file contents for `image`, with each cell `scale` pixels square.
+    pub fn encode(self, image: &Image, scale: usize) -> Vec<u8> {
+        let scale = scale.max(1);
+        match self {
+            Self::Png => png(image, scale),
+            Self::Ppm => ppm(image, scale),
+            Self::Svg => svg(image, scale).into_bytes(),
+        }
+    }
+}
+
+/// Rows of RGB bytes, each cell repeated `scale` times each way.
+fn scanlines(image: &Image, scale: usize) -> impl Iterator<Item = Vec<u8>> + '_ {
+    image.iter_rows().flat_map(move |row| {
+        let line = row
+            .iter()
+            .flat_map(|&Rgb(r, g, b)| [r, g, b].repeat(scale))
+            .collect::<Vec<_>>();
+        std::iter::repeat_n(line, scale)
+    })
+}
+
+fn ppm(image: &Image, scale: usize) -> Vec<u8> {
+    let mut out = format!(
+        "P6\n{} {}\n255\n",
+        image.cols() * scale,
+        image.rows() * scale
+    )
+    .into_bytes();
+    for line in scanlines(image, scale) {
+        out.extend(line);
+    }
+    out
+}
+
+fn svg(image: &Image, scale: usize) -> String {
+    let (width, height) = (image.cols() * scale, image.rows() * scale);
+    let mut out = format!(
+        "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{width}\" height=\"{height}\" shape-rendering=\"crispEdges\">\n"
+    );
+    // one rect per run of equal cells along a row
+    for (r, row) in image.iter_rows().enumerate() {
+        let mut c = 0;
+        while c < row.len() {
+            let run = row[c..].iter().take_while(|&&p| p == row[c]).count();
+            let Rgb(red, green, blue) = row[c];
+            let _ = writeln!(
+                out,
+                "<rect x=\"{}\" y=\"{}\" width=\"{}\" height=\"{scale}\" fill=\"#{red:02x}{green:02x}{blue:02x}\"/>",
+                c * scale,
+                r * scale,
+                run * scale,
+            );
+            c += run;
+        }
+    }
+    out.push_str("</svg>\n");
+    out
+}
+
+fn png(image: &Image, scale: usize) -> Vec<u8> {
+    let (width, height) = (image.cols() * scale, image.rows() * scale);
+
+    // each scanline starts with filter type 0 (none)
+    let mut raw = Vec::with_capacity(height * (1 + width * 3));
+    for line in scanlines(image, scale) {
+        raw.push(0);
+        raw.extend(line);
+    }
+
+    let mut header = Vec::new();
+    header.extend((width as u32).to_be_bytes());
+    header.extend((height as u32).to_be_bytes());
+    // 8 bits per channel, truecolour, default compression/filter/interlace
+    header.extend([8, 2, 0, 0, 0]);
+
+    let mut out = b"\x89PNG\r\n\x1a\n".to_vec();
+    chunk(&mut out, b"IHDR", &header);
+    chunk(&mut out, b"IDAT", &zlib_stored(&raw));
+    chunk(&mut out, b"IEND", &[]);
+    out
+}
+
+fn chunk(out: &mut Vec<u8>, kind: &[u8; 4], data: &[u8]) {
+    out.extend((data.len() as u32).to_be_bytes());
+    let start = out.len();
+    out.extend(kind);
+    out.extend(data);
+    let crc = crc32(&out[start..]);
+    out.extend(crc.to_be_bytes());
+}
+
+/// zlib stream of uncompressed deflate blocks
+fn zlib_stored(data: &[u8]) -> Vec<u8> {
+    let mut out = vec![0x78, 0x01];
+    let mut blocks = data.chunks(u16::MAX as usize).peekable();
+    if blocks.peek().is_none() {
+        out.extend([1, 0, 0, 0xff, 0xff]);
+    }
+    while let Some(block) = blocks.next() {
+        out.push(u8::from(blocks.peek().is_none()));
+        let len = block.len() as u16;
+        out.extend(len.to_le_bytes());
+        out.extend((!len).to_le_bytes());
+        out.extend(block);
+    }
+    out.extend(adler32(data).to_be_bytes());
+    out
+}
+
+fn crc32(data: &[u8]) -> u32 {
+    let mut crc = !0_u32;
+    for &byte in data {
+        crc ^= byte as u32;
+        for _ in 0..8 {
+            crc = if crc & 1 == 1 {
+                (crc >> 1) ^ 0xedb8_8320
+            } else {
+                crc >> 1
+            };
+        }
+    }
+    !crc
+}
+
+fn adler32(data: &[u8]) -> u32 {
+    let (mut a, mut b) = (1_u32, 0_u32);
+    for &byte in data {
+        a = (a + byte as u32) % 65521;
+        b = (b + a) % 65521;
+    }
+    (b << 16) | a
+}
+
+#[derive(Debug)]
+pub enum RenderError {
+    Parse(ParseError),
+    Io(io::Error),
+}
+
+/// Writes the pictures a day asks for into one directory, as
+/// `dayNN_<name>.<ext>` or, for frames, `dayNN_<name>_<0000>.<ext>`.
+#[derive(Debug)]
+pub struct Renderer {
+    dir: PathBuf,
+    format: Format,
+    scale: usize,
+    day: u32,
+    frames: HashMap<String, usize>,
+    written: Vec<PathBuf>,
+}
+
+impl Renderer {
+    pub fn new(dir: impl Into<PathBuf>, format: Format, scale: usize) -> Self {
+        Self {
+            dir: dir.into(),
+            format,
+            scale,
+            day: 0,
+            frames: HashMap::new(),
+            written: Vec::new(),
+        }
+    }
+
+    /// Names the files written from now on after `day`.
+    pub fn start_day(&mut self, day: u32) {
+        self.day = day;
+    }
+
+    pub fn image(&mut self, name: &str, image: &Image) -> io::Result<()> {
+        let file = format!("day{:02}_{name}.{}", self.day, self.format.extension());
+        self.write(&file, image)
+    }
+
+    /// The next numbered frame of the sequence `name`.
+    pub fn frame(&mut self, name: &str, image: &Image) -> io::Result<()> {
+        let key = format!("day{:02}_{name}", self.day);
+        let n = self.frames.entry(key.clone()).or_insert(0);
+        let file = format!("{key}_{n:04}.{}", self.format.extension());
+        *n += 1;
+        self.write(&file, image)
+    }
+
+    /// every file written so far
+    pub fn written(&self) -> &[PathBuf] {
+        &self.written
+    }
+
+    fn write(&mut self, file: &str, image: &Image) -> io::Result<()> {
+        fs::create_dir_all(&self.dir)?;
+        let path = Path::new(&self.dir).join(file);
+        fs::write(&path, self.format.encode(image, self.scale))
+            .map_err(|e| io::Error::new(e.kind(), format!("{}: {e}", path.display())))?;
+        self.written.push(path);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+fn checkerboard() -> Image {
+    let grid = Grid::new(2, 3, ());
+    image(&grid, |(r, c), _| {
+        if (r + c) % 2 == 0 {
+            Rgb::RED
+        } else {
+            Rgb::BLUE
+        }
+    })
+}
+
+#[test]
+fn ppm_and_svg() {
+    let image = checkerboard();
+
+    let ppm = Format::Ppm.encode(&image, 2);
+    let header = b"P6\n6 4\n255\n";
+    assert_eq!(&ppm[..header.len()], header);
+    assert_eq!(ppm.len(), header.len() + 6 * 4 * 3);
+    // second pixel of the first row is still the first cell
+    assert_eq!(&ppm[header.len() + 3..][..3], &[220, 50, 47]);
+
+    let svg = String::from_utf8(Format::Svg.encode(&image, 10)).unwrap();
+    assert_eq!(svg.matches("<rect").count(), 6);
+    assert!(svg.contains("<rect x=\"10\" y=\"0\" width=\"10\" height=\"10\" fill=\"#3c6edc\"/>"));
+
+    let mut solid = Grid::new(1, 4, Rgb::WHITE);
+    paint(&mut solid, [(0, 3), (5, 5)], Rgb::BLACK);
+    let svg = String::from_utf8(Format::Svg.encode(&solid, 1)).unwrap();
+    assert_eq!(svg.matches("<rect").count(), 2);
+}
+
+#[test]
+fn png_round_trip() {
+    let image = checkerboard();
+    let png = Format::Png.encode(&image, 3);
+    assert_eq!(&png[..8], b"\x89PNG\r\n\x1a\n");
+
+    // walk the chunks, checking each CRC, and undo the stored deflate blocks
+    let mut rest = &png[8..];
+    let mut kinds = Vec::new();
+    let mut idat: Vec<u8> = Vec::new();
+    while !rest.is_empty() {
+        let len = u32::from_be_bytes(rest[..4].try_into().unwrap()) as usize;
+        let (body, crc) = rest[4..].split_at(4 + len);
+        assert_eq!(crc32(body).to_be_bytes(), crc[..4]);
+        kinds.push(String::from_utf8(body[..4].to_vec()).unwrap());
+        if &body[..4] == b"IDAT" {
+            idat.extend(&body[4..]);
+        }
+        rest = &crc[4..];
+    }
+    assert_eq!(kinds, ["IHDR", "IDAT", "IEND"]);
+
+    let raw = &idat[2 + 5..idat.len() - 4];
+    assert_eq!(raw.len(), 6 * (1 + 9 * 3));
+    let expected = scanlines(&image, 3)
+        .flat_map(|line| [vec![0], line].concat())
+        .collect::<Vec<_>>();
+    assert_eq!(raw, expected);
+    assert_eq!(idat[idat.len() - 4..], adler32(&expected).to_be_bytes());
+
+    assert_eq!(crc32(b"IEND"), 0xae42_6082);
+}
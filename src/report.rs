@@ -0,0 +1,263 @@
+//! The results of `aoc run`, one [`Record`] per part, printed as a table to
+//! read or as JSON or CSV for scripts that compare runs. JSON is an array
+//! with one record per line, like the [`bench`](crate::bench) file.
+
+use std::fmt::Write;
+use std::time::Duration;
+
+use crate::params::Params;
+use crate::stats::Stats;
+
+/// One part of one day as it was run.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Record {
+    pub year: u32,
+    pub day: u32,
+    pub part: u32,
+    /// the answer, or why there is none
+    pub answer: Result<String, String>,
+    /// the Rust type of the part's answer, such as `u64`
+    pub answer_type: &'static str,
+    pub elapsed: Duration,
+    /// [`input::hash`](crate::input::hash) of the input, if it was read
+    /// whole
+    pub input_hash: Option<String>,
+    /// the parameters the part ran with, defaults included
+    pub params: Params,
+    /// what the part [counted](crate::stats), with `--stats`
+    pub stats: Stats,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+    Table,
+    Json,
+    Csv,
+}
+
+impl Format {
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "table" => Some(Self::Table),
+            "json" => Some(Self::Json),
+            "csv" => Some(Self::Csv),
+            _ => None,
+        }
+    }
+
+    pub fn write(self, records: &[Record]) -> String {
+        match self {
+            Self::Table => table(records),
+            Self::Json => json(records),
+            Self::Csv => csv(records),
+        }
+    }
+}
+
+/// With a `Stats` column if any part counted anything.
+fn table(records: &[Record]) -> String {
+    let width = records
+        .iter()
+        .map(|r| r.answer.as_ref().map_or_else(String::len, String::len))
+        .max()
+        .unwrap_or(0)
+        .max("Answer".len());
+    let stats = records.iter().any(|r| !r.stats.is_empty());
+
+    let mut out = format!(
+        "{:>4}  {:>3}  {:>4}  {:<width$}  {:>10}",
+        "Year", "Day", "Part", "Answer", "Time"
+    );
+    out.push_str(if stats { "  Stats\n" } else { "\n" });
+    for r in records {
+        let _ = write!(
+            out,
+            "{:>4}  {:>3}  {:>4}  {:<width$}  {:>10}",
+            r.year,
+            r.day,
+            r.part,
+            r.answer.as_ref().unwrap_or_else(|e| e),
+            format!("{:.2?}", r.elapsed),
+        );
+        if stats && !r.stats.is_empty() {
+            let _ = write!(out, "  {}", r.stats);
+        }
+        out.push('\n');
+    }
+    out
+}
+
+/// A failed part has a `null` answer and says why in `error`.
+fn json(records: &[Record]) -> String {
+    let string = |s: Option<&str>| s.map_or("null".to_string(), json_string);
+
+    let mut out = String::from("[\n");
+    for (i, r) in records.iter().enumerate() {
+        let params = r
+            .params
+            .iter()
+            .map(|(name, value)| format!("{}: {}", json_string(name), json_string(value)))
+            .collect::<Vec<_>>();
+        let stats = r
+            .stats
+            .iter()
+            .map(|(name, value)| format!("{}: {value}", json_string(name)))
+            .collect::<Vec<_>>();
+        let _ = write!(
+            out,
+            "  {{\"year\": {}, \"day\": {}, \"part\": {}, \"answer\": {}, \"answer_type\": \"{}\", \
+             \"elapsed_ns\": {}, \"input_hash\": {}, \"params\": {{{}}}, \"stats\": {{{}}}, \
+             \"error\": {}}}",
+            r.year,
+            r.day,
+            r.part,
+            string(r.answer.as_deref().ok()),
+            r.answer_type,
+            r.elapsed.as_nanos(),
+            string(r.input_hash.as_deref()),
+            params.join(", "),
+            stats.join(", "),
+            string(r.answer.as_ref().err().map(String::as_str)),
+        );
+        out.push_str(if i + 1 < records.len() { ",\n" } else { "\n" });
+    }
+    out.push_str("]\n");
+    out
+}
+
+fn json_string(s: &str) -> String {
+    let mut out = String::from("\"");
+    for c in s.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            c if c.is_control() => {
+                let _ = write!(out, "\\u{:04x}", c as u32);
+            }
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+    out
+}
+
+/// With a header row. `params` and `stats` are `name=value` pairs separated
+/// by `;`, as [`Params`] and [`Stats`] display them.
+fn csv(records: &[Record]) -> String {
+    let mut out =
+        String::from("year,day,part,answer,answer_type,elapsed_ns,input_hash,params,stats,error\n");
+    for r in records {
+        let fields = [
+            r.year.to_string(),
+            r.day.to_string(),
+            r.part.to_string(),
+            r.answer.clone().unwrap_or_default(),
+            r.answer_type.to_string(),
+            r.elapsed.as_nanos().to_string(),
+            r.input_hash.clone().unwrap_or_default(),
+            r.params.to_string(),
+            r.stats.to_string(),
+            r.answer.clone().err().unwrap_or_default(),
+        ];
+        let fields = fields.iter().map(|f| csv_field(f)).collect::<Vec<_>>();
+        out += &fields.join(",");
+        out.push('\n');
+    }
+    out
+}
+
+/// quoted if it holds a comma, quote or line break
+fn csv_field(field: &str) -> String {
+    if field.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
+
+#[cfg(test)]
+fn records() -> Vec<Record> {
+    let mut params = Params::default();
+    params.set("steps", "64");
+    params.set("start", "2,0");
+    vec![
+        Record {
+            year: 2023,
+            day: 21,
+            part: 1,
+            answer: Ok("3751".to_string()),
+            answer_type: "usize",
+            elapsed: Duration::from_micros(1500),
+            input_hash: Some("00000000deadbeef".to_string()),
+            params,
+            stats: [("memo_hits", 12), ("memo_misses", 30)]
+                .into_iter()
+                .collect(),
+        },
+        Record {
+            year: 2023,
+            day: 21,
+            part: 2,
+            answer: Err("61 (expected \"16\")".to_string()),
+            answer_type: "usize",
+            elapsed: Duration::ZERO,
+            input_hash: None,
+            params: Params::default(),
+            stats: Stats::default(),
+        },
+    ]
+}
+
+#[test]
+fn json_records() {
+    let json = Format::Json.write(&records());
+    let lines = json.lines().collect::<Vec<_>>();
+    assert_eq!(lines.len(), 4);
+    assert_eq!(
+        lines[1],
+        "  {\"year\": 2023, \"day\": 21, \"part\": 1, \"answer\": \"3751\", \
+         \"answer_type\": \"usize\", \"elapsed_ns\": 1500000, \
+         \"input_hash\": \"00000000deadbeef\", \
+         \"params\": {\"steps\": \"64\", \"start\": \"2,0\"}, \
+         \"stats\": {\"memo_hits\": 12, \"memo_misses\": 30}, \"error\": null},"
+    );
+    assert!(lines[2].contains("\"answer\": null,"));
+    assert!(lines[2]
+        .ends_with("\"params\": {}, \"stats\": {}, \"error\": \"61 (expected \\\"16\\\")\"}"));
+    assert_eq!(Format::Json.write(&[]), "[\n]\n");
+}
+
+#[test]
+fn csv_records() {
+    let csv = Format::Csv.write(&records());
+    let lines = csv.lines().collect::<Vec<_>>();
+    assert_eq!(
+        lines,
+        [
+            "year,day,part,answer,answer_type,elapsed_ns,input_hash,params,stats,error",
+            "2023,21,1,3751,usize,1500000,00000000deadbeef,\"steps=64;start=2,0\",\
+             memo_hits=12;memo_misses=30,",
+            "2023,21,2,,usize,0,,,,\"61 (expected \"\"16\"\")\"",
+        ]
+    );
+}
+
+#[test]
+fn table_records() {
+    let mut records = records();
+    assert_eq!(
+        Format::Table.write(&records),
+        "Year  Day  Part  Answer                    Time  Stats\n\
+         2023   21     1  3751                    1.50ms  memo_hits=12;memo_misses=30\n\
+         2023   21     2  61 (expected \"16\")      0.00ns\n"
+    );
+
+    records[0].stats = Stats::default();
+    assert_eq!(
+        Format::Table.write(&records),
+        "Year  Day  Part  Answer                    Time\n\
+         2023   21     1  3751                    1.50ms\n\
+         2023   21     2  61 (expected \"16\")      0.00ns\n"
+    );
+}
@@ -0,0 +1,312 @@
+//! `aoc new <year> <day>`: the files a new day starts from. A day module
+//! implementing [`Solution`](crate::Solution) is written under
+//! `src/y<year>/`, the year's `mod.rs` is regenerated from the days present
+//! so its `DAYS` list includes it, and a new year is declared in `lib.rs`
+//! and added to [`YEARS`](crate::years::YEARS). An example
+//! [`fixture`](crate::fixture) with no answers yet and the year's answers
+//! file are created alongside.
+
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use crate::answers::{self, Registry};
+use crate::fixture;
+use crate::years;
+
+/// an example fixture waiting for the puzzle's example and answers
+const EXAMPLE: &str = "# part1: <answer>\n# part2: <answer>\n---\n";
+
+/// line in `years.rs` that new years are inserted above
+const YEARS_MARKER: &str = "    // new years go above this line";
+
+/// Creates `day` of `year` in the crate at `root`, giving the files written
+/// or changed, relative to `root`. Nothing is overwritten: an existing day
+/// is an error.
+pub fn new_day(root: &Path, year: u32, day: u32) -> io::Result<Vec<PathBuf>> {
+    if !(1..=25).contains(&day) {
+        return Err(invalid(format!("no day {day}; days run from 1 to 25")));
+    }
+    if year == years::DEFAULT {
+        return Err(invalid(format!("{year} is complete in src/days")));
+    }
+    for file in ["src/lib.rs", "src/years.rs"] {
+        if !root.join(file).is_file() {
+            return Err(io::Error::new(
+                io::ErrorKind::NotFound,
+                format!("{}: not found; run from the crate root", file),
+            ));
+        }
+    }
+
+    let mut written = Vec::new();
+    let dir = PathBuf::from(format!("src/y{year}"));
+    fs::create_dir_all(root.join(&dir))?;
+
+    let day_file = dir.join(format!("day{day:02}.rs"));
+    if root.join(&day_file).exists() {
+        return Err(io::Error::new(
+            io::ErrorKind::AlreadyExists,
+            format!("{}: already exists", day_file.display()),
+        ));
+    }
+    fs::write(root.join(&day_file), day_module(year, day))?;
+    written.push(day_file);
+
+    let mod_file = dir.join("mod.rs");
+    fs::write(
+        root.join(&mod_file),
+        year_module(year, &days_in(&root.join(&dir))?),
+    )?;
+    written.push(mod_file);
+
+    let lib = PathBuf::from("src/lib.rs");
+    if let Some(text) = declare_year(&fs::read_to_string(root.join(&lib))?, year) {
+        fs::write(root.join(&lib), text)?;
+        written.push(lib);
+    }
+
+    let registry = PathBuf::from("src/years.rs");
+    if let Some(text) = register_year(&fs::read_to_string(root.join(&registry))?, year)? {
+        fs::write(root.join(&registry), text)?;
+        written.push(registry);
+    }
+
+    let example = fixture::dir(year, day).join("example.txt");
+    if !root.join(&example).exists() {
+        fs::create_dir_all(root.join(&example).parent().unwrap())?;
+        fs::write(root.join(&example), EXAMPLE)?;
+        written.push(example);
+    }
+
+    let answers = PathBuf::from(answers::file_for(year));
+    if !root.join(&answers).exists() {
+        fs::create_dir_all(root.join(&answers).parent().unwrap())?;
+        fs::write(root.join(&answers), Registry::default().to_string())?;
+        written.push(answers);
+    }
+
+    Ok(written)
+}
+
+fn invalid(message: String) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidInput, message)
+}
+
+/// The days with a `dayNN.rs` in `dir`, in order.
+fn days_in(dir: &Path) -> io::Result<Vec<u32>> {
+    let mut days = Vec::new();
+    for entry in fs::read_dir(dir)? {
+        let name = entry?.file_name();
+        let day = name.to_str().and_then(|n| {
+            n.strip_prefix("day")?
+                .strip_suffix(".rs")?
+                .parse::<u32>()
+                .ok()
+        });
+        days.extend(day);
+    }
+    days.sort_unstable();
+    Ok(days)
+}
+
+fn day_module(year: u32, day: u32) -> String {
+    format!(
+        r#"use crate::parse::ParseError;
+use crate::Solution;
+
+pub struct Day{day:02};
+
+impl Solution for Day{day:02} {{
+    const YEAR: u32 = {year};
+    const DAY: u32 = {day};
+    type Input<'a> = Vec<&'a str>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Vec<&str>, ParseError> {{
+        parse(input)
+    }}
+
+    fn part1(lines: &Vec<&str>) -> usize {{
+        part1(lines)
+    }}
+
+    fn part2(lines: &Vec<&str>) -> usize {{
+        part2(lines)
+    }}
+}}
+
+fn parse(input: &str) -> Result<Vec<&str>, ParseError> {{
+    Ok(input.lines().collect())
+}}
+
+fn part1(_lines: &[&str]) -> usize {{
+    todo!()
+}}
+
+fn part2(_lines: &[&str]) -> usize {{
+    todo!()
+}}
+"#
+    )
+}
+
+/// `mod.rs` for a year with `days`, laid out as rustfmt would.
+fn year_module(year: u32, days: &[u32]) -> String {
+    let mut out = format!(
+        "//! The {year} puzzles, one module per day; see [`years`](crate::years).\n\n\
+         use crate::DynSolution;\n\n"
+    );
+    for day in days {
+        out += &format!("mod day{day:02};\n");
+    }
+    out.push('\n');
+    for day in days {
+        out += &format!("pub use day{day:02}::Day{day:02};\n");
+    }
+    out.push('\n');
+
+    let head = "pub static DAYS: &[&dyn DynSolution] = &[";
+    let items = days
+        .iter()
+        .map(|d| format!("&Day{d:02}"))
+        .collect::<Vec<_>>();
+    let one_line = format!("{head}{}];", items.join(", "));
+    if one_line.len() <= 100 {
+        out += &one_line;
+        out.push('\n');
+        return out;
+    }
+    out += head;
+    out.push('\n');
+    // each item is preceded by a space, so the indent is one short
+    let mut line = String::from("   ");
+    for item in items {
+        if line.len() + item.len() + 2 > 100 {
+            out += &line;
+            out.push('\n');
+            line = String::from("   ");
+        }
+        line.push(' ');
+        line += &item;
+        line.push(',');
+    }
+    out += &line;
+    out += "\n];\n";
+    out
+}
+
+/// `lib` with `pub mod y<year>;` among the other modules in order, or
+/// `None` if it is already there.
+fn declare_year(lib: &str, year: u32) -> Option<String> {
+    let name = format!("y{year}");
+    fn module(line: &str) -> Option<&str> {
+        let line = line.strip_prefix("pub ").unwrap_or(line);
+        line.strip_prefix("mod ")?.strip_suffix(';')
+    }
+    let lines = lib.lines().collect::<Vec<_>>();
+    if lines.iter().any(|&l| module(l) == Some(&name)) {
+        return None;
+    }
+
+    let at = lines
+        .iter()
+        .position(|&l| module(l).is_some_and(|m| m > name.as_str()))
+        .or_else(|| {
+            lines
+                .iter()
+                .rposition(|&l| module(l).is_some())
+                .map(|i| i + 1)
+        })
+        .unwrap_or(lines.len());
+    let declaration = format!("pub mod {name};");
+    let mut lines = lines;
+    lines.insert(at, &declaration);
+    Some(lines.join("\n") + "\n")
+}
+
+/// `registry` (the text of `years.rs`) with `year` added to `YEARS`, or
+/// `None` if it is already there.
+fn register_year(registry: &str, year: u32) -> io::Result<Option<String>> {
+    let entry = format!("    ({year}, crate::y{year}::DAYS),\n");
+    if registry.contains(&entry) {
+        return Ok(None);
+    }
+    let Some(at) = registry.find(YEARS_MARKER) else {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidData,
+            format!("src/years.rs: `{}` not found", YEARS_MARKER.trim()),
+        ));
+    };
+    Ok(Some(format!(
+        "{}{entry}{}",
+        &registry[..at],
+        &registry[at..]
+    )))
+}
+
+#[test]
+fn scaffolds_years_and_days() {
+    let root = std::env::temp_dir().join(format!("aoc-new-{}", std::process::id()));
+    let _ = fs::remove_dir_all(&root);
+    fs::create_dir_all(root.join("src")).unwrap();
+    fs::write(root.join("src/lib.rs"), "pub mod days;\npub mod years;\n").unwrap();
+    fs::write(root.join("src/years.rs"), include_str!("years.rs")).unwrap();
+    let read = |path: &str| fs::read_to_string(root.join(path)).unwrap();
+
+    let written = new_day(&root, 2024, 5).unwrap();
+    assert_eq!(
+        written,
+        [
+            "src/y2024/day05.rs",
+            "src/y2024/mod.rs",
+            "src/lib.rs",
+            "src/years.rs",
+            "examples/2024/05/example.txt",
+            "answers/2024.txt",
+        ]
+        .map(PathBuf::from)
+    );
+    assert!(read("src/y2024/day05.rs").contains("const YEAR: u32 = 2024;\n    const DAY: u32 = 5;"));
+    assert_eq!(read("answers/2024.txt"), Registry::default().to_string());
+    let example = fixture::Fixture::load(&root, 2024, 5, "example").unwrap();
+    assert!(example.expected.is_empty() && example.input.is_empty());
+
+    // a second day only touches its year
+    let written = new_day(&root, 2024, 1).unwrap();
+    assert_eq!(written.len(), 3);
+    let module = read("src/y2024/mod.rs");
+    assert!(module.contains("mod day01;\nmod day05;\n"), "{module}");
+    assert!(module.contains("pub static DAYS: &[&dyn DynSolution] = &[&Day01, &Day05];\n"));
+
+    new_day(&root, 2015, 3).unwrap();
+    assert_eq!(
+        read("src/lib.rs"),
+        "pub mod days;\npub mod y2015;\npub mod y2024;\npub mod years;\n"
+    );
+    let registry = read("src/years.rs");
+    assert!(registry.contains(
+        "    (2024, crate::y2024::DAYS),\n    (2015, crate::y2015::DAYS),\n    // new years"
+    ));
+
+    let err = |year, day| new_day(&root, year, day).unwrap_err().kind();
+    assert_eq!(err(2024, 5), io::ErrorKind::AlreadyExists);
+    assert_eq!(err(2024, 26), io::ErrorKind::InvalidInput);
+    assert_eq!(err(years::DEFAULT, 1), io::ErrorKind::InvalidInput);
+    assert_eq!(read("src/y2024/mod.rs"), module);
+
+    fs::remove_dir_all(&root).unwrap();
+}
+
+#[test]
+fn long_day_lists_wrap() {
+    let module = year_module(2024, &(1..=25).collect::<Vec<_>>());
+    let list = module.split_once("= &[\n").unwrap().1;
+    let lines = list.lines().collect::<Vec<_>>();
+    assert_eq!(lines.len(), 4);
+    assert!(lines[0].starts_with("    &Day01, ") && lines[0].ends_with("&Day12,"));
+    assert_eq!(lines[2], "    &Day25,");
+    assert_eq!(lines[3], "];");
+    assert!(lines.iter().all(|l| l.len() <= 100));
+}
@@ -0,0 +1,251 @@
+use std::any;
+use std::fmt::Display;
+use std::io::{self, BufRead};
+use std::panic::{self, AssertUnwindSafe};
+use std::time::{Duration, Instant};
+
+use crate::params::{Param, Params};
+use crate::render::{RenderError, Renderer};
+use crate::stats::{self, Stats};
+use crate::visual::Visualizer;
+use crate::ParseError;
+
+/// Shape shared by every day: parse the input once, then solve each part
+/// from the parsed form.
+pub trait Solution {
+    /// the event the puzzle is from; see [`years`](crate::years)
+    const YEAR: u32 = crate::years::DEFAULT;
+
+    const DAY: u32;
+
+    /// false for puzzles without a second part (day 25)
+    const HAS_PART2: bool = true;
+
+    /// the numbers from the puzzle text that [`part1_with`](Self::part1_with)
+    /// and [`part2_with`](Self::part2_with) read
+    const PARAMS: &'static [Param] = &[];
+
+    /// parsed input; may borrow from the raw text
+    type Input<'a>;
+    type Answer1: Display;
+    type Answer2: Display;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError>;
+
+    fn part1(input: &Self::Input<'_>) -> Self::Answer1;
+
+    fn part2(input: &Self::Input<'_>) -> Self::Answer2;
+
+    /// [`part1`](Self::part1) with the puzzle's [`PARAMS`](Self::PARAMS)
+    /// as given in `params`, defaults included.
+    fn part1_with(input: &Self::Input<'_>, _params: &Params) -> Self::Answer1 {
+        Self::part1(input)
+    }
+
+    fn part2_with(input: &Self::Input<'_>, _params: &Params) -> Self::Answer2 {
+        Self::part2(input)
+    }
+
+    /// Properties of the real input the solution relies on beyond what the
+    /// puzzle text promises; see `aoc check`.
+    fn assumptions(_input: &Self::Input<'_>) -> Vec<Assumption> {
+        Vec::new()
+    }
+
+    /// Draws the input and what the solution found; see `aoc render`.
+    fn render(_input: &Self::Input<'_>, _out: &mut Renderer) -> io::Result<()> {
+        Ok(())
+    }
+
+    /// Plays the solution's simulation step by step; see `aoc run
+    /// --visualize`.
+    fn visualize(_input: &Self::Input<'_>, _out: &mut Visualizer) {}
+
+    /// Solves `parts` reading `input` a line at a time, for days that are
+    /// [`Streaming`](crate::stream::Streaming); `None` for the rest.
+    fn stream(
+        _input: &mut dyn BufRead,
+        _parts: &[u32],
+        _params: &Params,
+    ) -> Option<io::Result<Run>> {
+        None
+    }
+}
+
+/// One property of the input a solution relies on, and whether it held.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Assumption {
+    pub what: &'static str,
+    pub holds: bool,
+    /// what was actually found, e.g. where the start tile is
+    pub detail: String,
+}
+
+impl Assumption {
+    pub fn new(what: &'static str, holds: bool, detail: impl Into<String>) -> Self {
+        Self {
+            what,
+            holds,
+            detail: detail.into(),
+        }
+    }
+}
+
+/// One timed phase of a run; `value` is `None` when the phase panicked.
+#[derive(Debug, Clone)]
+pub struct Timed<T> {
+    pub value: Option<T>,
+    pub elapsed: Duration,
+    /// what the phase counted, with the `stats` feature
+    pub stats: Stats,
+}
+
+impl<T> Timed<T> {
+    pub(crate) fn measure(f: impl FnOnce() -> T) -> Self {
+        // anything counted outside a phase is not reported
+        stats::take();
+        let start = Instant::now();
+        // the default panic hook has already reported the message on stderr
+        let value = panic::catch_unwind(AssertUnwindSafe(f)).ok();
+        Self {
+            value,
+            elapsed: start.elapsed(),
+            stats: stats::take(),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Run {
+    /// parse errors come back located within the input
+    pub parse: Timed<Result<(), ParseError>>,
+    /// `(part, answer)` for every requested part the puzzle has
+    pub answers: Vec<(u32, Timed<String>)>,
+}
+
+/// Object-safe view of a [`Solution`] so tooling can hold every day in one
+/// list. Implemented for all solutions.
+pub trait DynSolution: Sync {
+    fn year(&self) -> u32;
+
+    fn day(&self) -> u32;
+
+    fn parts(&self) -> &'static [u32];
+
+    fn params(&self) -> &'static [Param];
+
+    /// the Rust type of `part`'s answer, without its path, like `u64`
+    fn answer_type(&self, part: u32) -> &'static str;
+
+    /// parses once, then solves each of `parts` (others are ignored)
+    fn run(&self, input: &str, parts: &[u32]) -> Run {
+        self.run_with(input, parts, &Params::default())
+    }
+
+    /// like `run`, with some of the day's parameters set by `params`
+    fn run_with(&self, input: &str, parts: &[u32], params: &Params) -> Run;
+
+    /// parses, then checks the solution's assumptions about the input
+    fn check(&self, input: &str) -> Result<Vec<Assumption>, ParseError>;
+
+    /// parses, then writes the solution's pictures through `out`
+    fn render(&self, input: &str, out: &mut Renderer) -> Result<(), RenderError>;
+
+    /// parses, then plays the solution's simulation through `out`
+    fn visualize(&self, input: &str, out: &mut Visualizer) -> Result<(), RenderError>;
+
+    /// like `run_with`, reading `input` a line at a time; `None` if the day
+    /// needs its whole input at once
+    fn stream(
+        &self,
+        input: &mut dyn BufRead,
+        parts: &[u32],
+        params: &Params,
+    ) -> Option<io::Result<Run>>;
+}
+
+impl<S: Solution + Sync> DynSolution for S {
+    fn year(&self) -> u32 {
+        S::YEAR
+    }
+
+    fn day(&self) -> u32 {
+        S::DAY
+    }
+
+    fn parts(&self) -> &'static [u32] {
+        if S::HAS_PART2 {
+            &[1, 2]
+        } else {
+            &[1]
+        }
+    }
+
+    fn params(&self) -> &'static [Param] {
+        S::PARAMS
+    }
+
+    fn answer_type(&self, part: u32) -> &'static str {
+        let name = match part {
+            1 => any::type_name::<S::Answer1>(),
+            _ => any::type_name::<S::Answer2>(),
+        };
+        name.rsplit("::").next().unwrap_or(name)
+    }
+
+    fn run_with(&self, input: &str, parts: &[u32], params: &Params) -> Run {
+        let mut parsed = None;
+        let parse = Timed::measure(|| match S::parse(input) {
+            Ok(p) => {
+                parsed = Some(p);
+                Ok(())
+            }
+            Err(e) => Err(e.locate(S::DAY, input)),
+        });
+
+        let answers = match parsed {
+            Some(parsed) => parts
+                .iter()
+                .filter(|p| self.parts().contains(p))
+                .map(|&part| {
+                    let params = params.resolve(S::PARAMS, part);
+                    let answer = Timed::measure(|| match part {
+                        1 => S::part1_with(&parsed, &params).to_string(),
+                        _ => S::part2_with(&parsed, &params).to_string(),
+                    });
+                    (part, answer)
+                })
+                .collect(),
+            None => Vec::new(),
+        };
+
+        Run { parse, answers }
+    }
+
+    fn check(&self, input: &str) -> Result<Vec<Assumption>, ParseError> {
+        let parsed = S::parse(input).map_err(|e| e.locate(S::DAY, input))?;
+        Ok(S::assumptions(&parsed))
+    }
+
+    fn render(&self, input: &str, out: &mut Renderer) -> Result<(), RenderError> {
+        let parsed = S::parse(input).map_err(|e| RenderError::Parse(e.locate(S::DAY, input)))?;
+        out.start_day(S::DAY);
+        S::render(&parsed, out).map_err(RenderError::Io)
+    }
+
+    fn visualize(&self, input: &str, out: &mut Visualizer) -> Result<(), RenderError> {
+        let parsed = S::parse(input).map_err(|e| RenderError::Parse(e.locate(S::DAY, input)))?;
+        out.resume();
+        S::visualize(&parsed, out);
+        out.take_error().map_or(Ok(()), |e| Err(RenderError::Io(e)))
+    }
+
+    fn stream(
+        &self,
+        input: &mut dyn BufRead,
+        parts: &[u32],
+        params: &Params,
+    ) -> Option<io::Result<Run>> {
+        S::stream(input, parts, params)
+    }
+}
@@ -0,0 +1,164 @@
+//! Counters that solvers bump to show where their work goes, such as heap
+//! pushes in [`graph::astar`](crate::graph::astar) or memo hits in day 12,
+//! for `aoc run --stats`.
+//!
+//! They are only kept with the `stats` feature. Without it, [`count`],
+//! [`add`] and [`peak`] are empty and inline away, so solvers call them
+//! freely in their hot loops. With it, each thread keeps its own tally:
+//! [`Timed`](crate::Timed) takes what each phase it measures counted, and
+//! [`par`](crate::par) adds its workers' tallies to the thread that spread
+//! the work.
+
+use std::fmt;
+
+#[cfg(feature = "stats")]
+use std::cell::RefCell;
+
+/// whether this build keeps counts
+pub const ENABLED: bool = cfg!(feature = "stats");
+
+#[cfg(feature = "stats")]
+thread_local! {
+    static TALLY: RefCell<Stats> = const { RefCell::new(Stats(Vec::new())) };
+}
+
+/// Counters by name, in the order they were first bumped.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Stats(Vec<Counter>);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Counter {
+    name: &'static str,
+    value: u64,
+    /// the largest value seen rather than a sum
+    peak: bool,
+}
+
+impl Stats {
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (&'static str, u64)> + '_ {
+        self.0.iter().map(|c| (c.name, c.value))
+    }
+
+    pub fn get(&self, name: &str) -> Option<u64> {
+        self.iter().find(|&(n, _)| n == name).map(|(_, v)| v)
+    }
+
+    /// Adds `other`'s counts to these; peaks keep the larger.
+    pub fn merge(&mut self, other: Stats) {
+        for c in other.0 {
+            self.bump(c.name, c.value, c.peak);
+        }
+    }
+
+    fn bump(&mut self, name: &'static str, value: u64, peak: bool) {
+        match self.0.iter_mut().find(|c| c.name == name) {
+            Some(c) if peak => c.value = c.value.max(value),
+            Some(c) => c.value += value,
+            None => self.0.push(Counter { name, value, peak }),
+        }
+    }
+}
+
+/// Counts, adding up any name given more than once.
+impl FromIterator<(&'static str, u64)> for Stats {
+    fn from_iter<I: IntoIterator<Item = (&'static str, u64)>>(counts: I) -> Self {
+        let mut stats = Stats::default();
+        for (name, value) in counts {
+            stats.bump(name, value, false);
+        }
+        stats
+    }
+}
+
+impl fmt::Display for Stats {
+    /// `name=value` pairs separated by `;`, like
+    /// [`Params`](crate::params::Params)
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, (name, value)) in self.iter().enumerate() {
+            if i > 0 {
+                f.write_str(";")?;
+            }
+            write!(f, "{name}={value}")?;
+        }
+        Ok(())
+    }
+}
+
+/// Adds one to `name`.
+#[inline(always)]
+pub fn count(name: &'static str) {
+    add(name, 1);
+}
+
+/// Adds `n` to `name`.
+#[inline(always)]
+#[cfg_attr(not(feature = "stats"), allow(unused_variables))]
+pub fn add(name: &'static str, n: usize) {
+    #[cfg(feature = "stats")]
+    TALLY.with_borrow_mut(|t| t.bump(name, n as u64, false));
+}
+
+/// Raises `name` to `n` if that is more than it has seen.
+#[inline(always)]
+#[cfg_attr(not(feature = "stats"), allow(unused_variables))]
+pub fn peak(name: &'static str, n: usize) {
+    #[cfg(feature = "stats")]
+    TALLY.with_borrow_mut(|t| t.bump(name, n as u64, true));
+}
+
+/// What the current thread has counted, starting it again from nothing.
+#[cfg(feature = "stats")]
+pub fn take() -> Stats {
+    TALLY.with_borrow_mut(std::mem::take)
+}
+
+#[cfg(not(feature = "stats"))]
+pub fn take() -> Stats {
+    Stats::default()
+}
+
+/// Adds `stats` to the current thread's tally, e.g. a worker's once it is
+/// done.
+#[cfg_attr(not(feature = "stats"), allow(unused_variables))]
+pub fn merge(stats: Stats) {
+    #[cfg(feature = "stats")]
+    TALLY.with_borrow_mut(|t| t.merge(stats));
+}
+
+#[test]
+fn counts_and_peaks() {
+    let mut stats = Stats::default();
+    stats.bump("pushes", 3, false);
+    stats.bump("frontier", 10, true);
+    stats.bump("pushes", 2, false);
+    stats.bump("frontier", 4, true);
+
+    let mut other = Stats::default();
+    other.bump("frontier", 12, true);
+    other.bump("pops", 1, false);
+    stats.merge(other);
+
+    assert_eq!(stats.to_string(), "pushes=5;frontier=12;pops=1");
+    assert_eq!(stats.get("pops"), Some(1));
+    assert_eq!(stats.get("hits"), None);
+}
+
+/// Only kept with the feature, and per phase.
+#[test]
+fn tally() {
+    take();
+    count("hits");
+    add("hits", 2);
+    peak("frontier", 7);
+    let stats = take();
+    if ENABLED {
+        assert_eq!(stats.to_string(), "hits=3;frontier=7");
+    } else {
+        assert!(stats.is_empty());
+    }
+    assert!(take().is_empty());
+}
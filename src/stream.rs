@@ -0,0 +1,197 @@
+//! Solving line-oriented days straight from a reader. Each line is parsed
+//! from one reused buffer and folded into a running total per part, so
+//! memory stays bounded however long the input is, e.g. a generated stress
+//! input piped into `aoc run 4 -`.
+
+use std::io::{self, BufRead};
+use std::panic::{self, AssertUnwindSafe};
+use std::time::{Duration, Instant};
+
+use crate::params::Params;
+use crate::parse::ParseError;
+use crate::solution::{Run, Solution, Timed};
+use crate::stats::{self, Stats};
+
+/// A [`Solution`] whose answers fold over the input one line at a time.
+pub trait Streaming: Solution {
+    /// one parsed line; may borrow from the line buffer
+    type Line<'a>;
+    /// what part 1 keeps between lines
+    type Total1: Default;
+    /// what part 2 keeps between lines
+    type Total2: Default;
+
+    fn parse_line(line: &str) -> Result<Self::Line<'_>, ParseError>;
+
+    /// part 1's total before any line, given its resolved parameters
+    fn start1(_params: &Params) -> Self::Total1 {
+        Self::Total1::default()
+    }
+
+    fn start2(_params: &Params) -> Self::Total2 {
+        Self::Total2::default()
+    }
+
+    fn add1(total: &mut Self::Total1, line: &Self::Line<'_>);
+
+    fn add2(total: &mut Self::Total2, line: &Self::Line<'_>);
+
+    fn finish1(total: Self::Total1) -> Self::Answer1;
+
+    fn finish2(total: Self::Total2) -> Self::Answer2;
+}
+
+/// Like [`DynSolution::run_with`](crate::DynSolution::run_with), reading
+/// `input` as it goes. The parse time covers reading and parsing; each
+/// part's time is what it spent on the lines plus finishing. A part that
+/// panics stops folding and fails on its own, as in a whole-input run.
+pub fn run<S: Streaming>(
+    input: &mut dyn BufRead,
+    parts: &[u32],
+    params: &Params,
+) -> io::Result<Run> {
+    let want = |part| parts.contains(&part) && (part == 1 || S::HAS_PART2);
+    let (want1, want2) = (want(1), want(2));
+
+    // `None` once a wanted part has panicked
+    let mut total1 = want1
+        .then(|| caught(|| S::start1(&params.resolve(S::PARAMS, 1))))
+        .flatten();
+    let mut total2 = want2
+        .then(|| caught(|| S::start2(&params.resolve(S::PARAMS, 2))))
+        .flatten();
+    let mut spent = [Duration::ZERO; 2];
+    let mut counted = [Stats::default(), Stats::default()];
+    let mut read_error = None;
+
+    let mut parse = Timed::measure(|| {
+        let mut buf = String::new();
+        for line_no in 1.. {
+            buf.clear();
+            match input.read_line(&mut buf) {
+                Ok(0) => break,
+                Ok(_) => (),
+                Err(e) => {
+                    read_error = Some(e);
+                    break;
+                }
+            }
+            // the same line ends as `str::lines`
+            let line = buf.strip_suffix('\n').unwrap_or(&buf);
+            let line = line.strip_suffix('\r').unwrap_or(line);
+
+            let parsed =
+                S::parse_line(line).map_err(|e| e.locate_in_line(S::DAY, line_no, line))?;
+            // what the parts count is kept apart from the parsing
+            let parsing = stats::take();
+            if let Some(total) = &mut total1 {
+                let start = Instant::now();
+                let added = caught(|| S::add1(total, &parsed));
+                spent[0] += start.elapsed();
+                counted[0].merge(stats::take());
+                if added.is_none() {
+                    total1 = None;
+                }
+            }
+            if let Some(total) = &mut total2 {
+                let start = Instant::now();
+                let added = caught(|| S::add2(total, &parsed));
+                spent[1] += start.elapsed();
+                counted[1].merge(stats::take());
+                if added.is_none() {
+                    total2 = None;
+                }
+            }
+            stats::merge(parsing);
+        }
+        Ok(())
+    });
+    if let Some(e) = read_error {
+        return Err(e);
+    }
+    parse.elapsed = parse.elapsed.saturating_sub(spent[0] + spent[1]);
+
+    let mut answers = Vec::new();
+    if matches!(parse.value, Some(Ok(()))) {
+        let [counted1, counted2] = counted;
+        if want1 {
+            let finish = total1.map(|total| move || S::finish1(total).to_string());
+            answers.push((1, finished(finish, spent[0], counted1)));
+        }
+        if want2 {
+            let finish = total2.map(|total| move || S::finish2(total).to_string());
+            answers.push((2, finished(finish, spent[1], counted2)));
+        }
+    }
+
+    Ok(Run { parse, answers })
+}
+
+/// `f()`, or `None` if it panicked; the default panic hook has already
+/// reported the message on stderr
+fn caught<T>(f: impl FnOnce() -> T) -> Option<T> {
+    panic::catch_unwind(AssertUnwindSafe(f)).ok()
+}
+
+/// A part's answer from `finish`, or a failed one if its fold panicked,
+/// with the time and counts of the fold added.
+fn finished(
+    finish: Option<impl FnOnce() -> String>,
+    spent: Duration,
+    counted: Stats,
+) -> Timed<String> {
+    let mut answer = match finish {
+        Some(finish) => Timed::measure(finish),
+        None => Timed {
+            value: None,
+            elapsed: Duration::ZERO,
+            stats: Stats::default(),
+        },
+    };
+    answer.elapsed += spent;
+    answer.stats.merge(counted);
+    answer
+}
+
+/// Both answers from streaming `input`, or the parse error.
+#[cfg(test)]
+pub(crate) fn answers<S: Streaming>(input: &str) -> Result<Vec<String>, ParseError> {
+    let run = run::<S>(&mut input.as_bytes(), &[1, 2], &Params::default()).unwrap();
+    run.parse.value.unwrap()?;
+    Ok(run
+        .answers
+        .into_iter()
+        .map(|(_, answer)| answer.value.unwrap())
+        .collect())
+}
+
+#[test]
+fn errors_point_at_the_line() {
+    use crate::days::Day04;
+
+    let input = "Card 1: 41 48 | 83 41\r\nCard 2: 13 x | 61\r\n";
+    let e = answers::<Day04>(input).unwrap_err();
+    assert_eq!((e.day, e.line, e.column), (Some(4), 2, 12));
+    assert_eq!(e.found, "x");
+    assert!(e.to_string().contains("2 | Card 2: 13 x | 61\n"), "{e}");
+
+    // only the requested part is worked out
+    let input = "Card 1: 41 48 | 83 41\n";
+    let run = run::<Day04>(&mut input.as_bytes(), &[2], &Params::default()).unwrap();
+    assert_eq!(run.answers.len(), 1);
+    assert_eq!(run.answers[0].0, 2);
+    assert_eq!(run.answers[0].1.value.as_deref(), Some("1"));
+}
+
+#[test]
+fn a_panicking_part_fails_alone() {
+    use crate::days::Day01;
+
+    // part 1 has no digit to find on the second line; part 2 reads "one"
+    let input = "1abc2\none\n";
+    let run = run::<Day01>(&mut input.as_bytes(), &[1, 2], &Params::default()).unwrap();
+    assert!(matches!(run.parse.value, Some(Ok(()))));
+    assert_eq!(run.answers.len(), 2);
+    assert_eq!(run.answers[0].1.value, None);
+    assert_eq!(run.answers[1].1.value.as_deref(), Some("23"));
+}
@@ -0,0 +1,233 @@
+//! Live view of a simulation in the terminal: every step is drawn as a
+//! coloured grid over the previous one, two cells to a character using
+//! half blocks.
+//!
+//! While it runs, space pauses and resumes, `s` (or `.`) steps one frame
+//! while paused, `+`/`-` change the speed and `q` stops the view. Keys are
+//! read from the controlling terminal, put into raw mode with `stty`, so
+//! this needs no terminal crate; without a terminal the frames are still
+//! drawn, just not controllable.
+
+use std::fmt::Write as _;
+use std::fs::File;
+use std::io::{self, Read, Write};
+use std::process::{Command, Stdio};
+use std::sync::mpsc::{self, Receiver, RecvTimeoutError};
+use std::thread;
+use std::time::Duration;
+
+use crate::render::{Image, Rgb};
+
+const HELP: &str = "space pause · s step · +/- speed · q stop";
+
+/// Draws frames to stdout, pacing them and handling keys between frames.
+#[derive(Debug)]
+pub struct Visualizer {
+    delay: Duration,
+    paused: bool,
+    stopped: bool,
+    frames: usize,
+    /// terminal `(lines, columns)`; frames are cropped to fit
+    size: Option<(usize, usize)>,
+    keys: Option<Receiver<u8>>,
+    /// `stty` settings to restore on drop
+    saved: Option<String>,
+    error: Option<io::Error>,
+}
+
+impl Visualizer {
+    pub fn new(delay: Duration) -> Self {
+        let saved = stty(&["-g"]).filter(|_| stty(&["-icanon", "-echo", "min", "1"]).is_some());
+        let keys = saved.as_ref().and_then(|_| {
+            let mut tty = File::open("/dev/tty").ok()?;
+            let (tx, rx) = mpsc::channel();
+            thread::spawn(move || {
+                let mut byte = [0];
+                while tty.read_exact(&mut byte).is_ok() && tx.send(byte[0]).is_ok() {}
+            });
+            Some(rx)
+        });
+        let size = stty(&["size"]).and_then(|s| {
+            let (lines, cols) = s.trim().split_once(' ')?;
+            Some((lines.parse().ok()?, cols.parse().ok()?))
+        });
+
+        Self {
+            delay,
+            paused: false,
+            stopped: false,
+            frames: 0,
+            size,
+            keys,
+            saved,
+            error: None,
+        }
+    }
+
+    /// Draws `image` under `title`, then waits out the delay (or until
+    /// stepped, if paused). Does nothing once stopped.
+    pub fn show(&mut self, title: &str, image: &Image) {
+        if self.stopped {
+            return;
+        }
+        let (lines, cols) = self.size.unwrap_or((usize::MAX, usize::MAX));
+        let mut frame = String::new();
+        if self.frames == 0 {
+            frame.push_str("\x1b[2J\x1b[?25l");
+        }
+        let _ = writeln!(frame, "\x1b[H{title}\x1b[K");
+        frame.push_str(&ansi(image, cols, lines.saturating_sub(2)));
+        let _ = write!(frame, "{HELP}\x1b[K\x1b[J");
+
+        let mut out = io::stdout().lock();
+        if let Err(e) = out.write_all(frame.as_bytes()).and_then(|_| out.flush()) {
+            self.error = Some(e);
+            self.stopped = true;
+            return;
+        }
+        self.frames += 1;
+        self.wait();
+    }
+
+    /// true after `q`, or after drawing failed
+    pub fn stopped(&self) -> bool {
+        self.stopped
+    }
+
+    /// number of frames drawn so far
+    pub fn frames(&self) -> usize {
+        self.frames
+    }
+
+    /// The error that stopped drawing, if any.
+    pub fn take_error(&mut self) -> Option<io::Error> {
+        self.error.take()
+    }
+
+    /// Lets a new simulation start after the previous one was stopped.
+    pub fn resume(&mut self) {
+        self.stopped = self.error.is_some();
+        self.paused = false;
+    }
+
+    fn wait(&mut self) {
+        let Some(keys) = &self.keys else {
+            thread::sleep(self.delay);
+            return;
+        };
+
+        loop {
+            let key = if self.paused {
+                keys.recv().ok()
+            } else {
+                match keys.recv_timeout(self.delay) {
+                    Ok(key) => Some(key),
+                    Err(RecvTimeoutError::Timeout) => return,
+                    Err(RecvTimeoutError::Disconnected) => None,
+                }
+            };
+            match key {
+                Some(b' ') => self.paused = !self.paused,
+                Some(b's' | b'.') if self.paused => return,
+                Some(b'+' | b'=') => self.delay /= 2,
+                Some(b'-') => self.delay = (self.delay * 2).max(Duration::from_millis(1)),
+                Some(b'q') => {
+                    self.stopped = true;
+                    return;
+                }
+                Some(_) => (),
+                // no more keys: carry on unpaced by them
+                None => {
+                    self.keys = None;
+                    return;
+                }
+            }
+        }
+    }
+}
+
+impl Drop for Visualizer {
+    fn drop(&mut self) {
+        if self.frames > 0 {
+            println!("\x1b[0m\x1b[?25h");
+            let _ = io::stdout().flush();
+        }
+        if let Some(saved) = &self.saved {
+            stty(&[saved.trim()]);
+        }
+    }
+}
+
+/// Runs `stty` on the controlling terminal, giving its output on success.
+fn stty(args: &[&str]) -> Option<String> {
+    let tty = File::open("/dev/tty").ok()?;
+    let output = Command::new("stty")
+        .args(args)
+        .stdin(tty)
+        .stderr(Stdio::null())
+        .output()
+        .ok()?;
+    output
+        .status
+        .success()
+        .then(|| String::from_utf8_lossy(&output.stdout).into_owned())
+}
+
+/// `image` as lines of upper half blocks, the top cell in the foreground
+/// colour and the one below in the background, cropped to `cols` columns
+/// and `lines` lines.
+pub fn ansi(image: &Image, cols: usize, lines: usize) -> String {
+    let rows = image.iter_rows().collect::<Vec<_>>();
+    let mut out = String::new();
+
+    for pair in rows.chunks(2).take(lines) {
+        let (mut fg, mut bg) = (None, None);
+        for c in 0..image.cols().min(cols) {
+            let top = pair[0][c];
+            if fg != Some(top) {
+                let Rgb(r, g, b) = top;
+                let _ = write!(out, "\x1b[38;2;{r};{g};{b}m");
+                fg = Some(top);
+            }
+            let below = pair.get(1).map(|row| row[c]);
+            if bg != Some(below) {
+                match below {
+                    Some(Rgb(r, g, b)) => {
+                        let _ = write!(out, "\x1b[48;2;{r};{g};{b}m");
+                    }
+                    None => out.push_str("\x1b[49m"),
+                }
+                bg = Some(below);
+            }
+            out.push('▀');
+        }
+        out.push_str("\x1b[0m\x1b[K\n");
+    }
+
+    out
+}
+
+#[test]
+fn half_blocks() {
+    use crate::grid::Grid;
+
+    let mut image = Grid::new(3, 4, Rgb::BLACK);
+    image[(1, 2)] = Rgb::RED;
+    image[(2, 0)] = Rgb::WHITE;
+
+    let text = ansi(&image, usize::MAX, usize::MAX);
+    let lines = text.lines().collect::<Vec<_>>();
+    assert_eq!(lines.len(), 2);
+    assert_eq!(lines[0].matches('▀').count(), 4);
+    // colours are only sent when they change
+    assert_eq!(
+        lines[0],
+        "\x1b[38;2;0;0;0m\x1b[48;2;0;0;0m▀▀\x1b[48;2;220;50;47m▀\x1b[48;2;0;0;0m▀\x1b[0m\x1b[K"
+    );
+    // the odd last row has nothing below it
+    assert!(lines[1].starts_with("\x1b[38;2;255;255;255m\x1b[49m▀"));
+
+    let cropped = ansi(&image, 2, 1);
+    assert_eq!(cropped.lines().count(), 1);
+    assert_eq!(cropped.matches('▀').count(), 2);
+}
@@ -0,0 +1,29 @@
+//! Every event's days, so the runner is not tied to 2023. A year is a
+//! module holding one file per day and a `DAYS` list of them, like
+//! [`days`](crate::days) for 2023; `aoc new <year> <day>` creates and
+//! registers both. Its inputs, examples and answers live under
+//! `inputs/<year>/`, `examples/<year>/` and `answers/<year>.txt`, except
+//! for 2023's, which keep their original places.
+
+use crate::days;
+use crate::DynSolution;
+
+/// the year `aoc` works on without `--year`
+pub const DEFAULT: u32 = 2023;
+
+/// `(year, days)`, one line per year; `aoc new` adds to the end
+pub static YEARS: &[(u32, &[&dyn DynSolution])] = &[
+    (2023, &days::DAYS),
+    // new years go above this line
+];
+
+pub fn days(year: u32) -> Option<&'static [&'static dyn DynSolution]> {
+    YEARS
+        .iter()
+        .find(|&&(y, _)| y == year)
+        .map(|&(_, days)| days)
+}
+
+pub fn get(year: u32, day: u32) -> Option<&'static dyn DynSolution> {
+    days(year)?.iter().copied().find(|s| s.day() == day)
+}